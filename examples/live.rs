@@ -0,0 +1,20 @@
+use std::io::stdout;
+use std::thread::sleep;
+use std::time::Duration;
+
+use rasciigraph::{Config, LivePlot};
+
+fn main() {
+    let mut live = LivePlot::new(
+        60,
+        Config::default()
+            .with_height(10)
+            .with_caption("sin(x)".to_string()),
+    );
+    for i in 0..200 {
+        live.push((f64::from(i) / 5.0).sin());
+        live.redraw(&mut stdout()).unwrap();
+        sleep(Duration::from_millis(50));
+    }
+    println!();
+}
@@ -0,0 +1,120 @@
+use crate::canvas::{Canvas, Style};
+use crate::{series_glyphs, Aggregation, Config, Layout};
+
+/// Lowest and highest sample of every column of a series downsampled with
+/// [`Aggregation::MinMax`].
+pub(crate) type Envelope = (Vec<f64>, Vec<f64>);
+
+/// Reduces `series` to `count` values, each standing for a run of neighbouring samples. Missing
+/// values are ignored and a run of them stays missing. [`Aggregation::MinMax`] returns the mean
+/// of each run along with its envelope.
+pub(crate) fn aggregate(
+    series: &[f64],
+    count: usize,
+    aggregation: Aggregation,
+) -> (Vec<f64>, Option<Envelope>) {
+    if aggregation == Aggregation::Lttb && count >= 2 {
+        return (lttb(series, count), None);
+    }
+    let len = series.len();
+    let runs: Vec<&[f64]> = (0..count)
+        .map(|c| &series[c * len / count..(c + 1) * len / count])
+        .collect();
+    // min and max skip NaN, leaving it only for runs of missing values
+    let fold = |run: &[f64], f: fn(f64, f64) -> f64| run.iter().copied().fold(f64::NAN, f);
+    let mean = |run: &[f64]| {
+        let values: Vec<f64> = run.iter().copied().filter(|v| !v.is_nan()).collect();
+        values.iter().sum::<f64>() / values.len() as f64
+    };
+    let reduced = runs
+        .iter()
+        .map(|run| match aggregation {
+            Aggregation::Min => fold(run, f64::min),
+            Aggregation::Max => fold(run, f64::max),
+            Aggregation::Mean | Aggregation::MinMax => mean(run),
+            _ => run
+                .iter()
+                .rev()
+                .copied()
+                .find(|v| !v.is_nan())
+                .unwrap_or(f64::NAN),
+        })
+        .collect();
+    let envelope = (aggregation == Aggregation::MinMax).then(|| {
+        (
+            runs.iter().map(|run| fold(run, f64::min)).collect(),
+            runs.iter().map(|run| fold(run, f64::max)).collect(),
+        )
+    });
+    (reduced, envelope)
+}
+
+/// Largest-Triangle-Three-Buckets: keeps the first and last samples, and from each bucket in
+/// between the sample spanning the largest triangle with the previous pick and the mean of the
+/// next bucket.
+fn lttb(series: &[f64], count: usize) -> Vec<f64> {
+    let len = series.len();
+    if count == 2 {
+        return vec![series[0], series[len - 1]];
+    }
+    let every = (len - 2) as f64 / (count - 2) as f64;
+    let bound = |bucket: usize| usize::min((bucket as f64 * every) as usize + 1, len);
+    let mut result = Vec::with_capacity(count);
+    result.push(series[0]);
+    let mut picked = 0;
+    for bucket in 0..count - 2 {
+        let (start, end, next_end) = (bound(bucket), bound(bucket + 1), bound(bucket + 2));
+        let next: Vec<usize> = (end..next_end).filter(|&x| !series[x].is_nan()).collect();
+        let (mean_x, mean_y) = if next.is_empty() {
+            (end as f64, series[picked])
+        } else {
+            let n = next.len() as f64;
+            (
+                next.iter().map(|&x| x as f64).sum::<f64>() / n,
+                next.iter().map(|&x| series[x]).sum::<f64>() / n,
+            )
+        };
+        let (picked_x, picked_y) = (picked as f64, series[picked]);
+        let area = |x: usize| {
+            ((picked_x - mean_x) * (series[x] - picked_y)
+                - (picked_x - x as f64) * (mean_y - picked_y))
+                .abs()
+        };
+        let best = (start..end)
+            .filter(|&x| !series[x].is_nan())
+            .max_by(|&a, &b| area(a).total_cmp(&area(b)));
+        match best {
+            Some(x) => {
+                picked = x;
+                result.push(series[x]);
+            }
+            None => result.push(f64::NAN),
+        }
+    }
+    result.push(series[len - 1]);
+    result
+}
+
+/// Draws the range of every column of the envelopes as a vertical line, to be drawn over by the
+/// series lines.
+pub(crate) fn draw_envelopes(
+    canvas: &mut Canvas,
+    envelopes: &[Envelope],
+    layout: &Layout,
+    left_pad: usize,
+    config: &Config,
+) {
+    for (i, (lows, highs)) in envelopes.iter().enumerate() {
+        let vertical = series_glyphs(config, i).vertical;
+        for (x, (&low, &high)) in lows.iter().zip(highs).enumerate() {
+            if low.is_nan() || high.is_nan() {
+                continue;
+            }
+            let low = layout.scaled(f64::max(low, layout.min));
+            let high = layout.scaled(f64::min(high, layout.max));
+            for y in low..=high {
+                canvas.set(layout.at(y), left_pad + x, vertical, Style::Series(i));
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::canvas::{Canvas, Style};
+use crate::{AnnotationPosition, Config, Layout};
+
+/// Text attached to a sample, see [`Config::with_annotation`].
+pub(crate) struct Annotation {
+    pub(crate) x: usize,
+    pub(crate) text: String,
+}
+
+/// Draws a marker column for every annotation within the plotted samples.
+pub(crate) fn draw_annotation_markers(
+    canvas: &mut Canvas,
+    layout: &Layout,
+    left_pad: usize,
+    config: &Config,
+) {
+    for annotation in &config.annotations {
+        if let Some(col) = column(layout, config, annotation.x) {
+            for row in 0..=layout.rows as usize {
+                canvas.set(row, left_pad + col, '┊', Style::Axis);
+            }
+        }
+    }
+}
+
+/// Prints the annotation texts above the plot, and the footnotes below it if configured.
+///
+/// Lines are inserted on top of the canvas, so this comes after everything addressing plot rows.
+pub(crate) fn draw_annotations(
+    canvas: &mut Canvas,
+    layout: &Layout,
+    left_pad: usize,
+    config: &Config,
+) {
+    let footnotes = config.annotation_position == AnnotationPosition::Footnotes;
+    let mut placed: Vec<(usize, String)> = config
+        .annotations
+        .iter()
+        .enumerate()
+        .filter_map(|(i, annotation)| {
+            let col = column(layout, config, annotation.x)?;
+            let text = if footnotes {
+                (i + 1).to_string()
+            } else {
+                annotation.text.clone()
+            };
+            Some((col, text))
+        })
+        .collect();
+    if placed.is_empty() {
+        return;
+    }
+    placed.sort_by_key(|(col, _)| *col);
+
+    // line 0 is right above the plot, every text takes the lowest line it fits on
+    let mut free: Vec<usize> = Vec::new();
+    let mut lines: Vec<Vec<(usize, &str)>> = Vec::new();
+    for (col, text) in &placed {
+        let line = match free.iter().position(|&f| f <= *col) {
+            Some(line) => line,
+            None => {
+                free.push(0);
+                lines.push(Vec::new());
+                free.len() - 1
+            }
+        };
+        free[line] = col + text.chars().count() + 1;
+        lines[line].push((*col, text));
+    }
+
+    let width = left_pad + layout.len_max;
+    for (line, texts) in lines.iter().enumerate() {
+        canvas.insert_row(0, width);
+        for (col, text) in texts {
+            canvas.put_str(0, left_pad + col, text, Style::Label);
+        }
+        // lead the markers of the lines above down to the plot
+        for (col, _) in lines[line + 1..].iter().flatten() {
+            if !texts.iter().any(|(c, t)| covers(*c, t, *col)) {
+                canvas.set(0, left_pad + col, '┊', Style::Axis);
+            }
+        }
+    }
+
+    if footnotes {
+        for (i, annotation) in config.annotations.iter().enumerate() {
+            if column(layout, config, annotation.x).is_some() {
+                let row = canvas.push_row();
+                let note = format!("{} {}", i + 1, annotation.text);
+                canvas.put_str(row, left_pad, &note, Style::Label);
+            }
+        }
+    }
+}
+
+fn covers(start: usize, text: &str, col: usize) -> bool {
+    (start..start + text.chars().count()).contains(&col)
+}
+
+/// Plot column of sample `x`, following the interpolation to the configured width.
+fn column(layout: &Layout, config: &Config, x: usize) -> Option<usize> {
+    if x >= layout.samples {
+        return None;
+    }
+    let per_cell = if config.braille { 2 } else { 1 };
+    let points = if config.width > 0 {
+        config.width as usize * per_cell
+    } else {
+        layout.samples
+    };
+    let point = if layout.samples > 1 {
+        (x as f64 * (points - 1) as f64 / (layout.samples - 1) as f64).round() as usize
+    } else {
+        0
+    };
+    Some(point / per_cell)
+}
@@ -0,0 +1,75 @@
+use crate::canvas::{Canvas, Style};
+use crate::{AreaMode, Config, Layout};
+
+/// Shading of each series, repeating after the fourth.
+const SHADES: [char; 4] = ['░', '▒', '▓', '█'];
+
+pub(crate) fn shade(series: usize) -> char {
+    SHADES[series % SHADES.len()]
+}
+
+/// Turns every series into the running total of it and the series before it. Missing values
+/// add nothing and stay missing.
+pub(crate) fn stack_series(series: &mut [Vec<f64>]) {
+    let len = series.iter().map(|s| s.len()).max().unwrap_or(0);
+    for x in 0..len {
+        let mut total = 0f64;
+        for value in series.iter_mut().filter_map(|s| s.get_mut(x)) {
+            if !value.is_nan() {
+                total += *value;
+                *value = total;
+            }
+        }
+    }
+}
+
+/// Shades the area below each series, to be drawn over by the lines.
+pub(crate) fn draw_areas(
+    canvas: &mut Canvas,
+    series: &[Vec<f64>],
+    layout: &Layout,
+    left_pad: usize,
+    config: &Config,
+) {
+    if config.area == AreaMode::Lines {
+        return;
+    }
+    let zero = layout.scale.forward(0f64);
+    let baseline = if zero.is_nan() {
+        0
+    } else {
+        layout.scaled(zero.clamp(layout.min, layout.max))
+    };
+    let len = series.iter().map(|s| s.len()).max().unwrap_or(0);
+    // Braille cells hold two samples side by side
+    let per_cell = if config.braille { 2 } else { 1 };
+
+    for x in 0..len.saturating_sub(1) {
+        let col = left_pad + x / per_cell;
+        // heights the next series is shaded down (or up) to, the baseline itself is shaded
+        let mut floor = (baseline, baseline, true);
+        for (i, s) in series.iter().enumerate() {
+            let (y0, y1) = match (s.get(x), s.get(x + 1)) {
+                (Some(v0), _) if v0.is_nan() => continue,
+                (Some(&v0), Some(&v1)) if !v1.is_nan() => (layout.scaled(v0), layout.scaled(v1)),
+                (Some(&v0), _) => (layout.scaled(v0), layout.scaled(v0)),
+                (None, _) => continue,
+            };
+            let (low, high) = (i32::min(y0, y1), i32::max(y0, y1));
+            let (floor_low, floor_high, inclusive) = floor;
+            let heights = if low > floor_high {
+                (floor_high + i32::from(!inclusive))..low
+            } else if high < floor_low {
+                (high + 1)..(floor_low + i32::from(inclusive))
+            } else {
+                0..0
+            };
+            for y in heights {
+                canvas.set(layout.at(y), col, shade(i), Style::Series(i));
+            }
+            if config.area == AreaMode::Stacked {
+                floor = (low, high, false);
+            }
+        }
+    }
+}
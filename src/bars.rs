@@ -0,0 +1,336 @@
+#[cfg(feature = "color")]
+use colored::ColoredString;
+
+use crate::canvas::{Canvas, Style};
+use crate::{
+    bounded_range, draw_caption, draw_hline_labels, draw_hlines, draw_y_axis, min_max,
+    value_formatter, BarOrder, Config, Layout, PlotError, Scale,
+};
+
+/// Eighth blocks from empty to full.
+const EIGHTHS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+/// Left eighth blocks from empty to full.
+const LEFT_EIGHTHS: [char; 9] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+/// Columns horizontal bars span when [`Config::with_width`] is not set.
+const HBAR_WIDTH: usize = 40;
+
+/// Plots `values` as vertical bars rising from zero, labeled with [`Config::with_x_labels`].
+///
+/// Bars are as wide as their labels unless [`Config::with_width`] sets the total width, which must
+/// fit a column per bar and the gaps between them.
+///
+/// # Panics
+///
+/// Panics on input [`try_plot_bars`] rejects with a [`PlotError`], except for empty input and input
+/// without finite values, which plot as an empty string.
+pub fn plot_bars(values: Vec<f64>, config: Config) -> String {
+    crate::unwrap_plot(try_plot_bars(values, config))
+}
+
+/// Fallible version of [`plot_bars`].
+pub fn try_plot_bars(values: Vec<f64>, config: Config) -> Result<String, PlotError> {
+    let canvas = draw_bars(&values, &config)?;
+    Ok(crate::render_plain(&canvas))
+}
+
+/// Plots bars colored with the first series color, see [`plot_bars`].
+///
+/// # Panics
+///
+/// Panics on input [`try_plot_bars_colored`] rejects with a [`PlotError`], except for empty input
+/// and input without finite values, which plot as an empty string.
+#[cfg(feature = "color")]
+pub fn plot_bars_colored(values: Vec<f64>, config: Config) -> ColoredString {
+    crate::unwrap_plot(try_plot_bars_colored(values, config))
+}
+
+/// Fallible version of [`plot_bars_colored`].
+#[cfg(feature = "color")]
+pub fn try_plot_bars_colored(values: Vec<f64>, config: Config) -> Result<ColoredString, PlotError> {
+    crate::check_series_colors(1, &config)?;
+    let canvas = draw_bars(&values, &config)?;
+    Ok(crate::render_colored(&canvas, &config))
+}
+
+/// Plots labeled horizontal bars, one item per line, with each value printed at the end of its bar.
+///
+/// Negative values extend left of the zero axis. The bars span [`Config::with_width`] columns, 40
+/// by default, and [`Config::with_bar_order`] sorts the items.
+///
+/// # Panics
+///
+/// Panics on input [`try_plot_hbar`] rejects with a [`PlotError`], except for empty input and input
+/// without finite values, which plot as an empty string.
+pub fn plot_hbar<L: AsRef<str>>(items: &[(L, f64)], config: Config) -> String {
+    crate::unwrap_plot(try_plot_hbar(items, config))
+}
+
+/// Fallible version of [`plot_hbar`].
+pub fn try_plot_hbar<L: AsRef<str>>(
+    items: &[(L, f64)],
+    config: Config,
+) -> Result<String, PlotError> {
+    let canvas = draw_hbar(items, &config)?;
+    Ok(crate::render_plain(&canvas))
+}
+
+/// Plots horizontal bars colored with the first series color, see [`plot_hbar`].
+///
+/// # Panics
+///
+/// Panics on input [`try_plot_hbar_colored`] rejects with a [`PlotError`], except for empty input
+/// and input without finite values, which plot as an empty string.
+#[cfg(feature = "color")]
+pub fn plot_hbar_colored<L: AsRef<str>>(items: &[(L, f64)], config: Config) -> ColoredString {
+    crate::unwrap_plot(try_plot_hbar_colored(items, config))
+}
+
+/// Fallible version of [`plot_hbar_colored`].
+#[cfg(feature = "color")]
+pub fn try_plot_hbar_colored<L: AsRef<str>>(
+    items: &[(L, f64)],
+    config: Config,
+) -> Result<ColoredString, PlotError> {
+    crate::check_series_colors(1, &config)?;
+    let canvas = draw_hbar(items, &config)?;
+    Ok(crate::render_colored(&canvas, &config))
+}
+
+fn draw_bars(values: &[f64], config: &Config) -> Result<Canvas, PlotError> {
+    let (mut canvas, left_pad, bar_width) = draw_bar_columns(values, config, &config.x_labels)?;
+    if !config.x_labels.is_empty() {
+        let row = canvas.push_row();
+        for (i, label) in config.x_labels.iter().take(values.len()).enumerate() {
+            let col = left_pad + i * (bar_width + 1);
+            let label: String = label.chars().take(bar_width).collect();
+            let pad = (bar_width - label.chars().count()) / 2;
+            canvas.put_str(row, col + pad, &label, Style::Label);
+        }
+    }
+    let len = values.len() * (bar_width + 1) - 1;
+    draw_caption(&mut canvas, config, left_pad, len);
+    Ok(canvas)
+}
+
+/// Draws the Y axis and a bar per value, returning the canvas with the column the bars start at
+/// and their width. Without a configured width bars are as wide as the widest of `labels`.
+pub(crate) fn draw_bar_columns(
+    values: &[f64],
+    config: &Config,
+    labels: &[String],
+) -> Result<(Canvas, usize, usize), PlotError> {
+    if values.is_empty() {
+        return Err(PlotError::EmptySeries { index: 0 });
+    }
+    // bars rise from zero, so it is always part of the range
+    let mut range = values.to_vec();
+    range.push(0f64);
+    let mut layout = Layout::new(&[range], values.len(), Scale::Linear, config)?;
+
+    let format_value = value_formatter(config, layout.min, layout.max);
+    let value_labels: Vec<String> = values
+        .iter()
+        .map(|&v| {
+            if config.bar_values && !v.is_nan() {
+                format_value(v)
+            } else {
+                String::new()
+            }
+        })
+        .collect();
+    let bar_width = if config.width > 0 {
+        if (config.width as usize) < 2 * values.len() - 1 {
+            return Err(PlotError::InvalidWidth(config.width));
+        }
+        (config.width as usize + 1) / values.len() - 1
+    } else {
+        labels
+            .iter()
+            .chain(value_labels.iter())
+            .map(|label| label.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(1)
+    };
+    layout.len_max = values.len() * (bar_width + 1) - 1;
+
+    let (mut canvas, left_pad) = draw_y_axis(&layout, config);
+    draw_hlines(&mut canvas, &layout, left_pad, config);
+    draw_hline_labels(&mut canvas, &layout, left_pad + layout.len_max + 1, config);
+    let rows = layout.rows as usize;
+    // headroom for the value of a bar reaching the top
+    let top = usize::from(config.bar_values);
+    if config.bar_values {
+        canvas.insert_row(0, left_pad + layout.len_max);
+    }
+    // cell `k` from the bottom spans heights `k - 1..k`, a value's height is its distance from
+    // the bottom label in rows
+    let interval = layout.max - layout.min;
+    let height = |value: f64| {
+        if interval > 0f64 {
+            ((value - layout.min) / interval * rows as f64).clamp(0f64, rows as f64)
+        } else {
+            0f64
+        }
+    };
+    let zero = height(0f64).round() as usize;
+    let row_of = |k: usize| top + rows - k;
+
+    let style = Style::Series(0);
+    for (i, &value) in values.iter().enumerate() {
+        if value.is_nan() {
+            continue;
+        }
+        let col = left_pad + i * (bar_width + 1);
+        let eighths = ((height(value) - zero as f64) * 8f64).round() as i64;
+        let (full, rest) = (
+            eighths.unsigned_abs() as usize / 8,
+            eighths.unsigned_abs() % 8,
+        );
+        let end_row = if eighths >= 0 {
+            for k in zero + 1..=zero + full {
+                fill(&mut canvas, row_of(k), col, bar_width, '█', style);
+            }
+            if rest > 0 {
+                let glyph = EIGHTHS[rest as usize];
+                fill(
+                    &mut canvas,
+                    row_of(zero + full + 1),
+                    col,
+                    bar_width,
+                    glyph,
+                    style,
+                );
+            }
+            row_of(zero + full + usize::from(rest > 0))
+        } else {
+            for k in (zero + 1 - full)..=zero {
+                fill(&mut canvas, row_of(k), col, bar_width, '█', style);
+            }
+            // only the upper half block exists for partially filled cells below zero
+            let half = rest >= 3;
+            if half {
+                fill(&mut canvas, row_of(zero - full), col, bar_width, '▀', style);
+            }
+            row_of(zero + 1 - full - usize::from(half))
+        };
+
+        if config.bar_values {
+            // the row inserted on top leaves room above the highest bar
+            let row = if eighths >= 0 {
+                end_row - 1
+            } else {
+                end_row + 1
+            };
+            let label = &value_labels[i];
+            let pad = bar_width.saturating_sub(label.chars().count()) / 2;
+            canvas.put_str(row, col + pad, label, Style::Label);
+        }
+    }
+    Ok((canvas, left_pad, bar_width))
+}
+
+fn draw_hbar<L: AsRef<str>>(items: &[(L, f64)], config: &Config) -> Result<Canvas, PlotError> {
+    if items.is_empty() {
+        return Err(PlotError::EmptySeries { index: 0 });
+    }
+    let mut items: Vec<(&str, f64)> = items.iter().map(|(l, v)| (l.as_ref(), *v)).collect();
+    match config.bar_order {
+        BarOrder::Input => {}
+        // missing values go last either way
+        BarOrder::Ascending => {
+            items.sort_by(|a, b| a.1.is_nan().cmp(&b.1.is_nan()).then(a.1.total_cmp(&b.1)))
+        }
+        BarOrder::Descending => {
+            items.sort_by(|a, b| a.1.is_nan().cmp(&b.1.is_nan()).then(b.1.total_cmp(&a.1)))
+        }
+    }
+
+    let mut values: Vec<f64> = items
+        .iter()
+        .map(|i| i.1)
+        .filter(|v| v.is_finite())
+        .collect();
+    values.push(0f64);
+    let (min, max) = min_max(&values);
+    let (min, max) = bounded_range(min, max, Scale::Linear, config)?;
+    let (min, max) = (min.min(0f64), max.max(0f64));
+
+    let width = if config.width > 0 {
+        config.width as usize
+    } else {
+        HBAR_WIDTH
+    };
+    let scale = if max > min {
+        width as f64 / (max - min)
+    } else {
+        0f64
+    };
+    let negative_width = (-min * scale).round() as usize;
+    let positive_width = width - negative_width;
+
+    let format_value = value_formatter(config, min, max);
+    let value_labels: Vec<String> = items
+        .iter()
+        .map(|&(_, v)| {
+            if v.is_nan() {
+                String::new()
+            } else {
+                format_value(v)
+            }
+        })
+        .collect();
+    let label_width = items.iter().map(|i| i.0.chars().count()).max().unwrap_or(0);
+    // negative values are printed left of their bars
+    let negative_label_width = items
+        .iter()
+        .zip(value_labels.iter())
+        .filter(|(i, _)| i.1 < 0f64)
+        .map(|(_, label)| label.chars().count() + 1)
+        .max()
+        .unwrap_or(0);
+    let axis = label_width + 1 + negative_label_width + negative_width;
+
+    let mut canvas = Canvas::new(axis + 1 + positive_width, items.len());
+    let style = Style::Series(0);
+    for (row, (&(label, value), value_label)) in items.iter().zip(value_labels.iter()).enumerate() {
+        canvas.put_str(row, 0, label, Style::Label);
+        canvas.set(row, axis, '┤', Style::Axis);
+        // missing values get neither a bar nor a value
+        if value >= 0f64 {
+            let eighths = ((value * scale * 8f64).round() as usize).min(positive_width * 8);
+            let (full, rest) = (eighths / 8, eighths % 8);
+            fill(&mut canvas, row, axis + 1, full, '█', style);
+            if rest > 0 {
+                canvas.set(row, axis + 1 + full, LEFT_EIGHTHS[rest], style);
+            }
+            let end = axis + 1 + full + usize::from(rest > 0);
+            canvas.put_str(row, end + 1, value_label, Style::Label);
+        } else if value < 0f64 {
+            // only the right half block exists for partially filled cells left of the axis
+            let halves = ((-value * scale * 2f64).round() as usize).min(negative_width * 2);
+            let (full, half) = (halves / 2, halves % 2 == 1);
+            fill(&mut canvas, row, axis - full, full, '█', style);
+            if half {
+                canvas.set(row, axis - full - 1, '▐', style);
+            }
+            let end = axis - full - usize::from(half);
+            let value_width = value_label.chars().count();
+            canvas.put_str(row, end - 1 - value_width, value_label, Style::Label);
+        }
+        canvas.trim_trailing(row);
+    }
+    draw_caption(
+        &mut canvas,
+        config,
+        label_width + 1,
+        axis + positive_width - label_width,
+    );
+    Ok(canvas)
+}
+
+fn fill(canvas: &mut Canvas, row: usize, col: usize, width: usize, glyph: char, style: Style) {
+    for x in col..col + width {
+        canvas.set(row, x, glyph, style);
+    }
+}
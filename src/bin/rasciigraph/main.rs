@@ -0,0 +1,624 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+use colored::Color;
+use rasciigraph::{
+    formatters, Aggregation, AnnotationPosition, AreaMode, Config, Interpolation, LegendPosition,
+    LivePlot, Scale, SeriesStyle,
+};
+
+mod table;
+
+use table::Table;
+
+/// Plots numbers read from stdin or a file as an ascii graph.
+///
+/// Numbers are separated by whitespace or newlines. With `--series N` every line holds one
+/// value for each of the N series. With `--csv` or `--tsv` the input is a table with a header
+/// row, every selected column being plotted as a series named after its header.
+///
+/// The flags cover the line plot options of `Config`. Options of the other plot kinds, such as
+/// scatter markers, histogram binning and bar ordering, have none.
+#[derive(Parser, Debug)]
+#[command(name = "rasciigraph", version)]
+struct Args {
+    /// File to read numbers from, stdin when omitted
+    file: Option<PathBuf>,
+
+    /// Read comma separated columns with a header row
+    #[arg(long, conflicts_with_all = ["tsv", "realtime", "series"])]
+    csv: bool,
+
+    /// Read tab separated columns with a header row
+    #[arg(long, conflicts_with_all = ["realtime", "series"])]
+    tsv: bool,
+
+    /// Comma separated table columns to plot, by header name or 1-based position,
+    /// all columns but the X-axis one when omitted
+    #[arg(long, value_delimiter = ',')]
+    columns: Vec<String>,
+
+    /// Table column labeling the X axis, by header name or 1-based position
+    #[arg(long)]
+    x_column: Option<String>,
+
+    /// Keep reading lines and redraw the plot of the latest values in place
+    #[arg(short, long)]
+    realtime: bool,
+
+    /// Number of latest values plotted in realtime mode
+    #[arg(long, default_value_t = 100)]
+    window: usize,
+
+    /// Maximum redraws per second in realtime mode
+    #[arg(long, default_value_t = 24.0)]
+    fps: f64,
+
+    /// Number of rows of the plot, derived from the data when 0
+    #[arg(short = 'H', long, default_value_t = 0)]
+    height: u32,
+
+    /// Number of columns the series are stretched or squeezed to, the data length when 0
+    #[arg(short, long, default_value_t = 0)]
+    width: u32,
+
+    /// Columns left of the Y axis
+    #[arg(short, long, default_value_t = 3)]
+    offset: u32,
+
+    /// Decimals of the Y-axis labels, derived from the data when omitted
+    #[arg(short, long)]
+    precision: Option<usize>,
+
+    /// Y-axis label format
+    #[arg(short, long, value_parser = ["si", "bytes", "duration", "percent"])]
+    format: Option<String>,
+
+    /// Caption drawn below the plot
+    #[arg(short, long)]
+    caption: Option<String>,
+
+    /// Lower bound of the Y axis
+    #[arg(long, allow_negative_numbers = true)]
+    lower_bound: Option<f64>,
+
+    /// Upper bound of the Y axis
+    #[arg(long, allow_negative_numbers = true)]
+    upper_bound: Option<f64>,
+
+    /// Clip the Y axis to the bounds instead of extending them to fit the data
+    #[arg(long)]
+    strict_bounds: bool,
+
+    /// Draw an X axis labeled with sample indices
+    #[arg(short = 'x', long)]
+    x_axis: bool,
+
+    /// Columns between X-axis ticks, implies --x-axis
+    #[arg(long)]
+    x_tick_spacing: Option<u32>,
+
+    /// Draw lines with Braille dots for higher resolution
+    #[arg(short = 'b', long)]
+    braille: bool,
+
+    /// Y-axis scale: linear, log10, log2 or symlog
+    #[arg(long, value_parser = parse_scale)]
+    y_scale: Option<Scale>,
+
+    /// Resampling to the plot width: linear, step, nearest or none
+    #[arg(long, value_parser = parse_interpolation)]
+    interpolation: Option<Interpolation>,
+
+    /// Downsampling to the plot width: mean, min, max, minmax, last or lttb
+    #[arg(long, value_parser = parse_aggregation)]
+    aggregation: Option<Aggregation>,
+
+    /// Horizontal reference line as VALUE or VALUE:LABEL, drawn dashed, may be repeated
+    #[arg(long, allow_hyphen_values = true, value_parser = parse_hline)]
+    hline: Vec<(f64, String)>,
+
+    /// Annotation of a sample as INDEX:TEXT, may be repeated
+    #[arg(long, value_parser = parse_annotation)]
+    annotate: Vec<(usize, String)>,
+
+    /// Where annotation texts are printed: above or footnotes
+    #[arg(long, value_parser = parse_annotation_position)]
+    annotation_position: Option<AnnotationPosition>,
+
+    /// Comma separated 1-based series numbers scaled on a secondary Y axis
+    #[arg(long, value_delimiter = ',', value_parser = parse_series_number)]
+    secondary_axis: Vec<usize>,
+
+    /// Area below the series: lines, filled or stacked
+    #[arg(long, value_parser = parse_area)]
+    area: Option<AreaMode>,
+
+    /// Number of series, each input line holding one value per series
+    #[arg(short = 'n', long, default_value_t = 1)]
+    series: usize,
+
+    /// Comma separated series legends
+    #[arg(short, long, value_delimiter = ',')]
+    legends: Vec<String>,
+
+    /// Where legends are drawn: below, right or inline
+    #[arg(long, value_parser = parse_legend_position)]
+    legend_position: Option<LegendPosition>,
+
+    /// Comma separated series styles: solid, heavy, double, dashed, dotted or step
+    #[arg(long, value_delimiter = ',', value_parser = parse_series_style)]
+    series_styles: Vec<SeriesStyle>,
+
+    /// Comma separated series colors, e.g. red,blue
+    #[arg(long, value_delimiter = ',', value_parser = parse_color)]
+    series_colors: Vec<Color>,
+
+    /// Caption color
+    #[arg(long, value_parser = parse_color)]
+    caption_color: Option<Color>,
+
+    /// Y-axis color
+    #[arg(long, value_parser = parse_color)]
+    axis_color: Option<Color>,
+
+    /// Y-axis label color
+    #[arg(long, value_parser = parse_color)]
+    label_color: Option<Color>,
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("rasciigraph: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), Box<dyn Error>> {
+    let input: Box<dyn BufRead + Send> = match &args.file {
+        Some(path) => Box::new(BufReader::new(File::open(path)?)),
+        None => Box::new(BufReader::new(io::stdin())),
+    };
+    if args.realtime {
+        return run_realtime(&args, input);
+    }
+
+    let mut config = config(&args);
+    let series = if args.csv || args.tsv {
+        let delimiter = if args.csv { ',' } else { '\t' };
+        let (series, legends, x_labels) = read_columns(input, delimiter, &args)?;
+        if args.legends.is_empty() {
+            config = config.with_series_legends(legends);
+        }
+        if let Some(x_labels) = x_labels {
+            config = config.with_x_labels(x_labels);
+        }
+        series
+    } else {
+        read_series(input, args.series)?
+    };
+
+    let plot = if colored(&args) {
+        let config = config.with_series_colors(series_colors(&args, series.len()));
+        rasciigraph::try_plot_many_colored(series, config)?.to_string()
+    } else {
+        rasciigraph::try_plot_many(series, config)?
+    };
+    println!("{}", plot);
+    Ok(())
+}
+
+/// Reads the selected table columns, returning them with their header names and the X-axis
+/// labels when `--x-column` is given.
+#[allow(clippy::type_complexity)]
+fn read_columns<R: BufRead>(
+    input: R,
+    delimiter: char,
+    args: &Args,
+) -> Result<(Vec<Vec<f64>>, Vec<String>, Option<Vec<String>>), Box<dyn Error>> {
+    let table = Table::read(input, delimiter)?;
+    let x_column = args
+        .x_column
+        .as_ref()
+        .map(|key| table.column(key))
+        .transpose()?;
+    let columns = if args.columns.is_empty() {
+        (0..table.header.len())
+            .filter(|&column| Some(column) != x_column)
+            .collect()
+    } else {
+        args.columns
+            .iter()
+            .map(|key| table.column(key))
+            .collect::<Result<Vec<usize>, String>>()?
+    };
+
+    let series = columns
+        .iter()
+        .map(|&column| table.numbers(column))
+        .collect::<Result<Vec<Vec<f64>>, String>>()?;
+    let legends = columns
+        .iter()
+        .map(|&column| table.header[column].clone())
+        .collect();
+    Ok((series, legends, x_column.map(|column| table.texts(column))))
+}
+
+/// Redraws the latest `--window` values in place as lines arrive, at most `--fps` times a second.
+/// Lines that are not numbers are reported on stderr and skipped.
+fn run_realtime(args: &Args, input: Box<dyn BufRead + Send>) -> Result<(), Box<dyn Error>> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for (number, line) in input.lines().enumerate() {
+            if sender.send(line.map(|line| (number, line))).is_err() {
+                break;
+            }
+        }
+    });
+
+    let colored = colored(args);
+    let config = config(args).with_series_colors(series_colors(args, args.series));
+    let mut live = LivePlot::with_series(args.series, args.window, config);
+    let interval = Duration::from_secs_f64(1f64 / args.fps.max(0.1));
+    let mut out = io::stdout();
+    let mut last_draw: Option<Instant> = None;
+    let mut dirty = false;
+    loop {
+        let timeout = last_draw.map_or(interval, |t| interval.saturating_sub(t.elapsed()));
+        match receiver.recv_timeout(timeout) {
+            Ok(line) => {
+                let (number, line) = line?;
+                match feed_line(&mut live, args.series, &line, number) {
+                    Ok(added) => dirty |= added,
+                    Err(message) => {
+                        // the message goes below the frame, the next one is drawn after it
+                        if live.keep_frame() {
+                            println!();
+                        }
+                        eprintln!("rasciigraph: {}, skipped", message);
+                    }
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+        if dirty && last_draw.is_none_or(|t| t.elapsed() >= interval) {
+            redraw(&mut live, &mut out, colored)?;
+            last_draw = Some(Instant::now());
+            dirty = false;
+        }
+    }
+    if dirty {
+        redraw(&mut live, &mut out, colored)?;
+    }
+    println!();
+    Ok(())
+}
+
+/// Adds the values of input line `number` to `live`, returning whether there were any. A line
+/// that is not all numbers adds nothing and is returned as an error.
+fn feed_line(
+    live: &mut LivePlot,
+    series: usize,
+    line: &str,
+    number: usize,
+) -> Result<bool, String> {
+    let values = parse_line(line, number)?;
+    if series == 1 {
+        values.iter().for_each(|value| live.push(*value));
+    } else if !values.is_empty() {
+        live.push_many(&values);
+    }
+    Ok(!values.is_empty())
+}
+
+fn redraw(live: &mut LivePlot, out: &mut io::Stdout, colored: bool) -> io::Result<()> {
+    let result = if colored {
+        live.redraw_colored(out)
+    } else {
+        live.redraw(out)
+    };
+    match result {
+        // nothing plottable in the window yet, e.g. only NaN values
+        Err(err) if err.kind() == io::ErrorKind::InvalidInput => Ok(()),
+        result => result,
+    }
+}
+
+fn colored(args: &Args) -> bool {
+    !args.series_colors.is_empty()
+        || args.caption_color.is_some()
+        || args.axis_color.is_some()
+        || args.label_color.is_some()
+}
+
+fn config(args: &Args) -> Config {
+    let mut config = Config::default()
+        .with_height(args.height)
+        .with_width(args.width)
+        .with_offset(args.offset)
+        .with_strict_bounds(args.strict_bounds)
+        .with_x_axis(args.x_axis)
+        .with_braille(args.braille)
+        .with_series_legends(args.legends.clone())
+        .with_series_styles(args.series_styles.clone())
+        .with_secondary_axis(args.secondary_axis.clone());
+    if let Some(precision) = args.precision {
+        config = config.with_precision(precision);
+    }
+    config = match args.format.as_deref() {
+        Some("si") => config.with_value_formatter(formatters::si(args.precision.unwrap_or(1))),
+        Some("bytes") => {
+            config.with_value_formatter(formatters::bytes(args.precision.unwrap_or(1)))
+        }
+        Some("duration") => {
+            config.with_value_formatter(formatters::duration(args.precision.unwrap_or(1)))
+        }
+        Some("percent") => {
+            config.with_value_formatter(formatters::percent(args.precision.unwrap_or(1)))
+        }
+        _ => config,
+    };
+    if let Some(caption) = &args.caption {
+        config = config.with_caption(caption.clone());
+    }
+    if let Some(bound) = args.lower_bound {
+        config = config.with_lower_bound(bound);
+    }
+    if let Some(bound) = args.upper_bound {
+        config = config.with_upper_bound(bound);
+    }
+    if let Some(spacing) = args.x_tick_spacing {
+        config = config.with_x_tick_spacing(spacing);
+    }
+    if let Some(scale) = args.y_scale {
+        config = config.with_y_scale(scale);
+    }
+    if let Some(interpolation) = args.interpolation {
+        config = config.with_interpolation(interpolation);
+    }
+    if let Some(aggregation) = args.aggregation {
+        config = config.with_aggregation(aggregation);
+    }
+    if let Some(position) = args.legend_position {
+        config = config.with_legend_position(position);
+    }
+    for (value, label) in &args.hline {
+        config = config.with_hline(*value, label.clone(), SeriesStyle::Dashed);
+    }
+    for (x, text) in &args.annotate {
+        config = config.with_annotation(*x, text.clone());
+    }
+    if let Some(position) = args.annotation_position {
+        config = config.with_annotation_position(position);
+    }
+    if let Some(area) = args.area {
+        config = config.with_area(area);
+    }
+
+    if let Some(color) = args.caption_color {
+        config = config.with_caption_color(color);
+    }
+    if let Some(color) = args.axis_color {
+        config = config.with_axis_color(color);
+    }
+    if let Some(color) = args.label_color {
+        config = config.with_label_color(color);
+    }
+    config
+}
+
+/// Colors for `series` series, cycling through `--series-colors`.
+fn series_colors(args: &Args, series: usize) -> Vec<Color> {
+    let required = usize::max(series, args.legends.len());
+    if args.series_colors.is_empty() {
+        return vec![Color::White; required];
+    }
+    args.series_colors
+        .iter()
+        .copied()
+        .cycle()
+        .take(usize::max(required, args.series_colors.len()))
+        .collect()
+}
+
+/// Reads whitespace separated numbers, one series when `series` is 1 and otherwise one value
+/// per series on each line.
+fn read_series<R: BufRead>(input: R, series: usize) -> Result<Vec<Vec<f64>>, Box<dyn Error>> {
+    let series = usize::max(series, 1);
+    let mut values = vec![Vec::new(); series];
+    for (number, line) in input.lines().enumerate() {
+        let numbers = parse_line(&line?, number)?;
+        if series == 1 {
+            values[0].extend(numbers);
+        } else if !numbers.is_empty() {
+            for (i, s) in values.iter_mut().enumerate() {
+                s.push(numbers.get(i).copied().unwrap_or(f64::NAN));
+            }
+        }
+    }
+    Ok(values)
+}
+
+fn parse_line(line: &str, number: usize) -> Result<Vec<f64>, String> {
+    line.split_whitespace()
+        .map(|token| {
+            token
+                .parse::<f64>()
+                .map_err(|_| format!("line {}: invalid number `{}`", number + 1, token))
+        })
+        .collect()
+}
+
+fn parse_color(src: &str) -> Result<Color, String> {
+    src.parse::<Color>()
+        .map_err(|_| format!("unknown color `{}`", src))
+}
+
+fn parse_series_style(src: &str) -> Result<SeriesStyle, String> {
+    match src {
+        "solid" => Ok(SeriesStyle::Solid),
+        "heavy" => Ok(SeriesStyle::Heavy),
+        "double" => Ok(SeriesStyle::Double),
+        "dashed" => Ok(SeriesStyle::Dashed),
+        "dotted" => Ok(SeriesStyle::Dotted),
+        "step" => Ok(SeriesStyle::Step),
+        _ => Err(format!("unknown series style `{}`", src)),
+    }
+}
+
+fn parse_legend_position(src: &str) -> Result<LegendPosition, String> {
+    match src {
+        "below" => Ok(LegendPosition::Below),
+        "right" => Ok(LegendPosition::Right),
+        "inline" => Ok(LegendPosition::Inline),
+        _ => Err(format!("unknown legend position `{}`", src)),
+    }
+}
+
+fn parse_hline(src: &str) -> Result<(f64, String), String> {
+    let (value, label) = src.split_once(':').unwrap_or((src, ""));
+    let value = value
+        .parse::<f64>()
+        .map_err(|_| format!("invalid number `{}`", value))?;
+    Ok((value, label.to_string()))
+}
+
+fn parse_annotation(src: &str) -> Result<(usize, String), String> {
+    let (x, text) = src
+        .split_once(':')
+        .ok_or_else(|| format!("expected INDEX:TEXT, got `{}`", src))?;
+    let x = x
+        .parse::<usize>()
+        .map_err(|_| format!("invalid sample index `{}`", x))?;
+    Ok((x, text.to_string()))
+}
+
+fn parse_annotation_position(src: &str) -> Result<AnnotationPosition, String> {
+    match src {
+        "above" => Ok(AnnotationPosition::Above),
+        "footnotes" => Ok(AnnotationPosition::Footnotes),
+        _ => Err(format!("unknown annotation position `{}`", src)),
+    }
+}
+
+/// Parses a 1-based series number into its index.
+fn parse_series_number(src: &str) -> Result<usize, String> {
+    match src.parse::<usize>() {
+        Ok(number) if number > 0 => Ok(number - 1),
+        _ => Err(format!("invalid series number `{}`", src)),
+    }
+}
+
+fn parse_area(src: &str) -> Result<AreaMode, String> {
+    match src {
+        "lines" => Ok(AreaMode::Lines),
+        "filled" => Ok(AreaMode::Filled),
+        "stacked" => Ok(AreaMode::Stacked),
+        _ => Err(format!("unknown area mode `{}`", src)),
+    }
+}
+
+fn parse_scale(src: &str) -> Result<Scale, String> {
+    match src {
+        "linear" => Ok(Scale::Linear),
+        "log10" => Ok(Scale::Log10),
+        "log2" => Ok(Scale::Log2),
+        "symlog" => Ok(Scale::SymLog),
+        _ => Err(format!("unknown scale `{}`", src)),
+    }
+}
+
+fn parse_interpolation(src: &str) -> Result<Interpolation, String> {
+    match src {
+        "linear" => Ok(Interpolation::Linear),
+        "step" => Ok(Interpolation::Step),
+        "nearest" => Ok(Interpolation::Nearest),
+        "none" => Ok(Interpolation::None),
+        _ => Err(format!("unknown interpolation `{}`", src)),
+    }
+}
+
+fn parse_aggregation(src: &str) -> Result<Aggregation, String> {
+    match src {
+        "mean" => Ok(Aggregation::Mean),
+        "min" => Ok(Aggregation::Min),
+        "max" => Ok(Aggregation::Max),
+        "minmax" => Ok(Aggregation::MinMax),
+        "last" => Ok(Aggregation::Last),
+        "lttb" => Ok(Aggregation::Lttb),
+        _ => Err(format!("unknown aggregation `{}`", src)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{feed_line, parse_annotation, parse_hline, parse_series_number, read_series};
+    use rasciigraph::{plot, plot_many, Config, LivePlot};
+
+    #[test]
+    fn test_read_series() {
+        let input = "1 2\n3\n\n4.5 nan\n";
+        assert_eq!(read_series(input.as_bytes(), 1).unwrap()[0].len(), 5);
+
+        let series = read_series("1 2\n3\n\n4 5 6\n".as_bytes(), 2).unwrap();
+        assert_eq!(series[0], vec![1.0, 3.0, 4.0]);
+        assert_eq!(series[1][0], 2.0);
+        assert!(series[1][1].is_nan());
+        assert_eq!(series[1][2], 5.0);
+
+        assert!(read_series("1 x".as_bytes(), 1).is_err());
+    }
+
+    #[test]
+    fn test_parse_flags() {
+        assert_eq!(parse_hline("-2.5"), Ok((-2.5, String::new())));
+        assert_eq!(
+            parse_hline("250:p99: 250ms"),
+            Ok((250.0, "p99: 250ms".to_string()))
+        );
+        assert!(parse_hline("high:250").is_err());
+
+        assert_eq!(parse_annotation("3:deploy"), Ok((3, "deploy".to_string())));
+        assert!(parse_annotation("deploy").is_err());
+
+        assert_eq!(parse_series_number("2"), Ok(1));
+        assert!(parse_series_number("0").is_err());
+    }
+
+    #[test]
+    fn test_feed_line() {
+        let mut live = LivePlot::new(3, Config::default());
+        assert_eq!(feed_line(&mut live, 1, "1 2", 0), Ok(true));
+        assert_eq!(feed_line(&mut live, 1, "", 1), Ok(false));
+        assert_eq!(
+            feed_line(&mut live, 1, "3 x", 2),
+            Err("line 3: invalid number `x`".to_string())
+        );
+        // only the latest window of values is plotted
+        assert_eq!(feed_line(&mut live, 1, "3 4", 3), Ok(true));
+        assert_eq!(
+            live.render().unwrap(),
+            plot(vec![2.0, 3.0, 4.0], Config::default())
+        );
+
+        let mut live = LivePlot::with_series(2, 2, Config::default());
+        for (number, line) in ["1 5", "oops", "2", "3 7"].iter().enumerate() {
+            let _ = feed_line(&mut live, 2, line, number);
+        }
+        assert_eq!(
+            live.render().unwrap(),
+            plot_many(vec![vec![2.0, 3.0], vec![f64::NAN, 7.0]], Config::default())
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use std::error::Error;
+use std::io::BufRead;
+
+/// Delimiter separated records with a header row.
+pub(crate) struct Table {
+    pub(crate) header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Reads a header line followed by records, skipping blank lines. Fields may be quoted with
+    /// `"` to contain the delimiter, quotes inside quoted fields are doubled.
+    pub(crate) fn read<R: BufRead>(input: R, delimiter: char) -> Result<Self, Box<dyn Error>> {
+        let mut records = Vec::new();
+        for line in input.lines() {
+            let line = line?;
+            let line = line.trim_end_matches('\r');
+            if !line.trim().is_empty() {
+                records.push(split_record(line, delimiter));
+            }
+        }
+        if records.is_empty() {
+            return Err("missing header row".into());
+        }
+        let header = records.remove(0);
+        Ok(Table {
+            header,
+            rows: records,
+        })
+    }
+
+    /// Finds a column by header name or by 1-based position.
+    pub(crate) fn column(&self, key: &str) -> Result<usize, String> {
+        if let Some(index) = self.header.iter().position(|name| name == key) {
+            return Ok(index);
+        }
+        match key.parse::<usize>() {
+            Ok(position) if position >= 1 && position <= self.header.len() => Ok(position - 1),
+            _ => Err(format!("unknown column `{}`", key)),
+        }
+    }
+
+    /// Values of a column, empty cells being NaN gaps.
+    pub(crate) fn numbers(&self, column: usize) -> Result<Vec<f64>, String> {
+        self.rows
+            .iter()
+            .enumerate()
+            .map(|(row, record)| {
+                let cell = record.get(column).map_or("", |cell| cell.trim());
+                if cell.is_empty() {
+                    return Ok(f64::NAN);
+                }
+                cell.parse::<f64>().map_err(|_| {
+                    format!(
+                        "row {}, column `{}`: invalid number `{}`",
+                        row + 2,
+                        self.header[column],
+                        cell
+                    )
+                })
+            })
+            .collect()
+    }
+
+    pub(crate) fn texts(&self, column: usize) -> Vec<String> {
+        self.rows
+            .iter()
+            .map(|record| record.get(column).cloned().unwrap_or_default())
+            .collect()
+    }
+}
+
+fn split_record(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if quoted || field.is_empty() => quoted = !quoted,
+            c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Table;
+
+    #[test]
+    fn test_read_table() {
+        let input = "time,\"rate, req/s\",errors\n10:00,1.5,\n\n10:01,2,\"3\"\n";
+        let table = Table::read(input.as_bytes(), ',').unwrap();
+        assert_eq!(table.header, vec!["time", "rate, req/s", "errors"]);
+        assert_eq!(table.column("errors"), Ok(2));
+        assert_eq!(table.column("2"), Ok(1));
+        assert!(table.column("4").is_err());
+        assert_eq!(table.numbers(1), Ok(vec![1.5, 2.0]));
+        let errors = table.numbers(2).unwrap();
+        assert!(errors[0].is_nan());
+        assert_eq!(errors[1], 3.0);
+        assert_eq!(table.texts(0), vec!["10:00", "10:01"]);
+        assert!(table.numbers(0).is_err());
+    }
+}
@@ -0,0 +1,166 @@
+#[cfg(feature = "color")]
+use colored::ColoredString;
+
+use crate::canvas::{Canvas, Style};
+use crate::{
+    draw_caption, draw_hline_labels, draw_hlines, draw_x_axis, draw_y_axis, scale_series, Config,
+    Layout, PlotError,
+};
+
+/// Open, high, low and close values of one period.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Ohlc {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+impl Ohlc {
+    pub fn new(open: f64, high: f64, low: f64, close: f64) -> Self {
+        Ohlc {
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+
+    fn is_up(&self) -> bool {
+        self.close >= self.open
+    }
+}
+
+/// Plots one candle per period: a `│` wick from low to high and a body from open to close, `┃`
+/// when the period closed up and `█` when it closed down.
+///
+/// Candles are one column apart unless [`Config::with_width`] is set. A width below the number
+/// of periods merges neighbouring ones into a single candle. Periods with a missing (NaN) value
+/// are left empty.
+///
+/// # Panics
+///
+/// Panics on input [`try_plot_candles`] rejects with a [`PlotError`], except for empty input and
+/// input without finite values, which plot as an empty string.
+pub fn plot_candles(candles: &[Ohlc], config: Config) -> String {
+    crate::unwrap_plot(try_plot_candles(candles, config))
+}
+
+/// Fallible version of [`plot_candles`].
+pub fn try_plot_candles(candles: &[Ohlc], config: Config) -> Result<String, PlotError> {
+    let canvas = draw_candles(candles, &config, false)?;
+    Ok(crate::render_plain(&canvas))
+}
+
+/// Plots candles using `config` colors, see [`plot_candles`]. Bodies are all drawn with `█`,
+/// in the first series color when the period closed up and the second when it closed down.
+///
+/// # Panics
+///
+/// Panics on input [`try_plot_candles_colored`] rejects with a [`PlotError`], except for empty
+/// input and input without finite values, which plot as an empty string.
+#[cfg(feature = "color")]
+pub fn plot_candles_colored(candles: &[Ohlc], config: Config) -> ColoredString {
+    crate::unwrap_plot(try_plot_candles_colored(candles, config))
+}
+
+/// Fallible version of [`plot_candles_colored`].
+#[cfg(feature = "color")]
+pub fn try_plot_candles_colored(
+    candles: &[Ohlc],
+    config: Config,
+) -> Result<ColoredString, PlotError> {
+    crate::check_series_colors(2, &config)?;
+    let canvas = draw_candles(candles, &config, true)?;
+    Ok(crate::render_colored(&canvas, &config))
+}
+
+/// Merges every run of neighbouring candles sharing a column into one.
+fn merge(candles: &[Ohlc], columns: usize) -> Vec<Ohlc> {
+    let len = candles.len();
+    (0..columns)
+        .map(|c| {
+            let run = &candles[c * len / columns..(c + 1) * len / columns];
+            Ohlc {
+                open: run[0].open,
+                high: run.iter().map(|c| c.high).fold(f64::NAN, f64::max),
+                low: run.iter().map(|c| c.low).fold(f64::NAN, f64::min),
+                close: run[run.len() - 1].close,
+            }
+        })
+        .collect()
+}
+
+fn draw_candles(candles: &[Ohlc], config: &Config, colored: bool) -> Result<Canvas, PlotError> {
+    if config.width == 1 {
+        return Err(PlotError::InvalidWidth(config.width));
+    }
+    if candles.is_empty() {
+        return Err(PlotError::EmptySeries { index: 0 });
+    }
+    let columns = if config.width > 0 {
+        config.width as usize
+    } else {
+        candles.len()
+    };
+    let periods = candles.len();
+    let candles = if columns < periods {
+        merge(candles, columns)
+    } else {
+        candles.to_vec()
+    };
+    let column_of = |i: usize| {
+        if candles.len() > 1 {
+            (i as f64 * (columns - 1) as f64 / (candles.len() - 1) as f64).round() as usize
+        } else {
+            (columns - 1) / 2
+        }
+    };
+
+    // open, high, low and close rows, mapped onto the Y scale together
+    let mut values: Vec<Vec<f64>> = vec![
+        candles.iter().map(|c| c.open).collect(),
+        candles.iter().map(|c| c.high).collect(),
+        candles.iter().map(|c| c.low).collect(),
+        candles.iter().map(|c| c.close).collect(),
+    ];
+    values.iter_mut().flatten().for_each(|v| {
+        if !v.is_finite() {
+            *v = f64::NAN;
+        }
+    });
+    scale_series(&mut values, config.y_scale)?;
+    let mut layout = Layout::new(&values, columns, config.y_scale, config)?;
+    layout.len_max = columns;
+    // only strict bounds leave values outside of the range
+    let row_of = |value: f64| layout.at(layout.scaled(value.clamp(layout.min, layout.max)));
+
+    let (mut canvas, left_pad) = draw_y_axis(&layout, config);
+    draw_hlines(&mut canvas, &layout, left_pad, config);
+    for (i, candle) in candles.iter().enumerate() {
+        let [open, high, low, close] = [0, 1, 2, 3].map(|field| values[field][i]);
+        if [open, high, low, close].iter().any(|v| v.is_nan()) {
+            continue;
+        }
+        let col = left_pad + column_of(i);
+        let (style, body) = match (candle.is_up(), colored) {
+            (true, false) => (Style::Series(0), '┃'),
+            (true, true) => (Style::Series(0), '█'),
+            (false, _) => (Style::Series(1), '█'),
+        };
+        // rows count from the top, so the high is the first one
+        for row in row_of(f64::max(high, low))..=row_of(f64::min(high, low)) {
+            canvas.set(row, col, '│', style);
+        }
+        for row in row_of(f64::max(open, close))..=row_of(f64::min(open, close)) {
+            canvas.set(row, col, body, style);
+        }
+    }
+
+    draw_hline_labels(&mut canvas, &layout, left_pad + columns + 1, config);
+    if config.x_axis {
+        draw_x_axis(&mut canvas, columns, periods, left_pad, config);
+    }
+    draw_caption(&mut canvas, config, left_pad, columns);
+    Ok(canvas)
+}
@@ -0,0 +1,127 @@
+/// What a cell shows, resolved to a color when rendering with the `color` feature.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Style {
+    Default,
+    Axis,
+    Label,
+    Caption,
+    Series(usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Cell {
+    pub(crate) ch: char,
+    pub(crate) style: Style,
+}
+
+impl Cell {
+    const BLANK: Cell = Cell {
+        ch: ' ',
+        style: Style::Default,
+    };
+}
+
+/// Grid of styled characters every plot is drawn on before being turned into text.
+///
+/// Rows may differ in length, rows appended below the plot (axes, captions, legends) are only as
+/// long as their content.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Canvas {
+    rows: Vec<Vec<Cell>>,
+}
+
+impl Canvas {
+    /// Creates a blank canvas of `height` rows of `width` cells.
+    pub(crate) fn new(width: usize, height: usize) -> Self {
+        Canvas {
+            rows: vec![vec![Cell::BLANK; width]; height],
+        }
+    }
+
+    /// Overwrites this canvas with `other`, reusing the allocated rows.
+    pub(crate) fn copy_from(&mut self, other: &Canvas) {
+        self.rows.clone_from(&other.rows);
+    }
+
+    /// Sets a single cell, writes outside of the canvas are ignored.
+    pub(crate) fn set(&mut self, row: usize, col: usize, ch: char, style: Style) {
+        if let Some(cell) = self.rows.get_mut(row).and_then(|r| r.get_mut(col)) {
+            *cell = Cell { ch, style };
+        }
+    }
+
+    /// Writes `text` starting at `col`, growing the row with blanks as needed.
+    pub(crate) fn put_str(&mut self, row: usize, col: usize, text: &str, style: Style) {
+        if row >= self.rows.len() {
+            self.rows.resize(row + 1, Vec::new());
+        }
+        let line = &mut self.rows[row];
+        for (i, ch) in text.chars().enumerate() {
+            if col + i >= line.len() {
+                line.resize(col + i + 1, Cell::BLANK);
+            }
+            line[col + i] = Cell { ch, style };
+        }
+    }
+
+    /// Inserts a blank row of `width` cells before `row`.
+    pub(crate) fn insert_row(&mut self, row: usize, width: usize) {
+        self.rows.insert(row, vec![Cell::BLANK; width]);
+    }
+
+    /// Number of cells in `row`, 0 past the last row.
+    pub(crate) fn row_len(&self, row: usize) -> usize {
+        self.rows.get(row).map_or(0, |line| line.len())
+    }
+
+    /// Appends an empty row, returning its index.
+    pub(crate) fn push_row(&mut self) -> usize {
+        self.rows.push(Vec::new());
+        self.rows.len() - 1
+    }
+
+    /// Removes the last cell of `row` if it is blank.
+    pub(crate) fn trim_last(&mut self, row: usize) {
+        if let Some(line) = self.rows.get_mut(row) {
+            if line.last() == Some(&Cell::BLANK) {
+                line.pop();
+            }
+        }
+    }
+
+    /// Removes all blank cells at the end of `row`.
+    pub(crate) fn trim_trailing(&mut self, row: usize) {
+        if let Some(line) = self.rows.get_mut(row) {
+            while line.last() == Some(&Cell::BLANK) {
+                line.pop();
+            }
+        }
+    }
+
+    /// Joins the rows with newlines, passing each run of equally styled cells through `paint`.
+    pub(crate) fn render<F>(&self, paint: F) -> String
+    where
+        F: Fn(&str, Style) -> String,
+    {
+        let mut out = String::new();
+        for (i, row) in self.rows.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            let mut run = String::new();
+            let mut run_style = Style::Default;
+            for cell in row {
+                if cell.style != run_style && !run.is_empty() {
+                    out.push_str(&paint(&run, run_style));
+                    run.clear();
+                }
+                run_style = cell.style;
+                run.push(cell.ch);
+            }
+            if !run.is_empty() {
+                out.push_str(&paint(&run, run_style));
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+
+use crate::Binning;
+
+/// Reasons a plot can not be rendered.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum PlotError {
+    /// The series at `index` has no samples.
+    EmptySeries { index: usize },
+    /// The requested width can not hold the plot. It must be 0 (auto) or at least 2 for lines,
+    /// and leave bars a column each plus the gaps between them.
+    InvalidWidth(u32),
+    /// None of the series contain a finite value to scale the plot with.
+    NoFiniteValues,
+    /// The configured Y-axis bounds are not finite or `lower` is above `upper`.
+    InvalidBounds {
+        lower: Option<f64>,
+        upper: Option<f64>,
+    },
+    /// Fewer series colors than series (or legends) were configured.
+    MissingSeriesColors { required: usize, provided: usize },
+    /// The histogram binning asks for no bins, more than [`MAX_BINS`](crate::MAX_BINS) or bins
+    /// that are not a positive finite width.
+    InvalidBinning(Binning),
+    /// A logarithmic Y scale got a zero or negative value, [`Scale::SymLog`](crate::Scale::SymLog)
+    /// plots those.
+    NonPositiveValue(f64),
+}
+
+impl fmt::Display for PlotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlotError::EmptySeries { index } => write!(f, "series {} is empty", index),
+            PlotError::InvalidWidth(width) => {
+                write!(f, "width {} is too narrow for the plot", width)
+            }
+            PlotError::NoFiniteValues => write!(f, "series contain no finite values"),
+            PlotError::InvalidBounds { lower, upper } => {
+                write!(f, "invalid bounds, lower {:?} upper {:?}", lower, upper)
+            }
+            PlotError::MissingSeriesColors { required, provided } => write!(
+                f,
+                "{} series colors required but only {} provided",
+                required, provided
+            ),
+            PlotError::InvalidBinning(binning) => write!(f, "invalid binning {:?}", binning),
+            PlotError::NonPositiveValue(value) => {
+                write!(
+                    f,
+                    "value {} can not be plotted on a logarithmic scale",
+                    value
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PlotError {}
@@ -0,0 +1,71 @@
+//! Built-in Y-axis label formatters for [`Config::with_value_formatter`](crate::Config::with_value_formatter).
+//!
+//! ```
+//! use rasciigraph::{formatters, plot, Config};
+//!
+//! let chart = plot(
+//!     vec![1_200.0, 350_000.0, 2_500_000.0],
+//!     Config::default().with_height(4).with_value_formatter(formatters::si(1)),
+//! );
+//! assert!(chart.contains("2.5M"));
+//! ```
+
+const SI_LARGE: [&str; 6] = ["k", "M", "G", "T", "P", "E"];
+const SI_SMALL: [&str; 4] = ["m", "µ", "n", "p"];
+const BINARY: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Scales values with SI prefixes, e.g. `1234567.0` becomes `1.2M` and `0.00012` becomes `120.0µ`.
+pub fn si(precision: usize) -> impl Fn(f64) -> String + Send + Sync + Clone {
+    move |value| {
+        if value == 0f64 || !value.is_finite() {
+            return format!("{:.*}", precision, value);
+        }
+        let exponent = ((value.abs().log10() / 3f64).floor() as i32).clamp(-4, 6);
+        let scaled = value / 1000f64.powi(exponent);
+        let prefix = match exponent {
+            e if e > 0 => SI_LARGE[e as usize - 1],
+            e if e < 0 => SI_SMALL[(-e) as usize - 1],
+            _ => "",
+        };
+        format!("{:.*}{}", precision, scaled, prefix)
+    }
+}
+
+/// Formats byte counts with binary prefixes, e.g. `1536.0` becomes `1.5KiB`.
+pub fn bytes(precision: usize) -> impl Fn(f64) -> String + Send + Sync + Clone {
+    move |value| {
+        let exponent = if value.abs() < 1f64 || !value.is_finite() {
+            0
+        } else {
+            ((value.abs().log2() / 10f64).floor() as i32).clamp(0, 6)
+        };
+        let scaled = value / 1024f64.powi(exponent);
+        format!("{:.*}{}", precision, scaled, BINARY[exponent as usize])
+    }
+}
+
+/// Formats durations given in seconds, e.g. `0.25` becomes `250.0ms` and `90.0` becomes `1.5min`.
+pub fn duration(precision: usize) -> impl Fn(f64) -> String + Send + Sync + Clone {
+    move |value| {
+        let abs = value.abs();
+        let (scaled, unit) = if abs >= 3600f64 {
+            (value / 3600f64, "h")
+        } else if abs >= 60f64 {
+            (value / 60f64, "min")
+        } else if abs >= 1f64 || abs == 0f64 || !abs.is_finite() {
+            (value, "s")
+        } else if abs >= 1e-3 {
+            (value * 1e3, "ms")
+        } else if abs >= 1e-6 {
+            (value * 1e6, "µs")
+        } else {
+            (value * 1e9, "ns")
+        };
+        format!("{:.*}{}", precision, scaled, unit)
+    }
+}
+
+/// Formats ratios as percentages, e.g. `0.25` becomes `25.0%`.
+pub fn percent(precision: usize) -> impl Fn(f64) -> String + Send + Sync + Clone {
+    move |value| format!("{:.*}%", precision, value * 100f64)
+}
@@ -0,0 +1,191 @@
+#[cfg(feature = "color")]
+use colored::ColoredString;
+
+use crate::bars::draw_bar_columns;
+use crate::canvas::{Canvas, Style};
+use crate::{draw_caption, label_precision, Config, PlotError};
+
+/// Most bins [`bins`] divides values into, more make no readable chart.
+pub const MAX_BINS: usize = 200;
+
+/// How [`histogram`] divides the range of its values into bins, at most [`MAX_BINS`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Binning {
+    /// The given number of equally wide bins.
+    Count(usize),
+    /// Bins of the given width, aligned to multiples of it.
+    Width(f64),
+    /// `log2(n) + 1` bins, suited to roughly normal data.
+    #[default]
+    Sturges,
+    /// Bins `2 * IQR / cbrt(n)` wide, robust against outliers. Falls back to [`Binning::Sturges`]
+    /// when far outliers would make more than [`MAX_BINS`] bins.
+    FreedmanDiaconis,
+}
+
+/// A histogram bin counting the values in `start..end`, the last bin includes its end.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bin {
+    pub start: f64,
+    pub end: f64,
+    pub count: usize,
+}
+
+/// Sorts the finite `values` into bins according to `binning`.
+///
+/// ```
+/// use rasciigraph::{bins, Binning};
+///
+/// let bins = bins(&[1.0, 2.0, 2.5, 4.0], Binning::Width(2.0)).unwrap();
+/// assert_eq!(bins.iter().map(|b| b.count).collect::<Vec<_>>(), vec![1, 3]);
+/// assert_eq!((bins[0].start, bins[1].end), (0.0, 4.0));
+/// ```
+pub fn bins(values: &[f64], binning: Binning) -> Result<Vec<Bin>, PlotError> {
+    let mut finite: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    if values.is_empty() {
+        return Err(PlotError::EmptySeries { index: 0 });
+    }
+    if finite.is_empty() {
+        return Err(PlotError::NoFiniteValues);
+    }
+    finite.sort_by(f64::total_cmp);
+    let (min, max) = (finite[0], finite[finite.len() - 1]);
+    let sturges = || (finite.len() as f64).log2().ceil() as usize + 1;
+
+    let (start, width, count) = match binning {
+        Binning::Count(count) if count == 0 || count > MAX_BINS => {
+            return Err(PlotError::InvalidBinning(binning))
+        }
+        Binning::Width(width) if !(width > 0f64 && width.is_finite()) => {
+            return Err(PlotError::InvalidBinning(binning))
+        }
+        Binning::Width(width) => {
+            let start = (min / width).floor() * width;
+            let count = ((max - start) / width).ceil().max(1f64);
+            if count > MAX_BINS as f64 {
+                return Err(PlotError::InvalidBinning(binning));
+            }
+            (start, width, count as usize)
+        }
+        _ if max == min => (min - 0.5, 1f64, 1),
+        Binning::Count(count) => (min, (max - min) / count as f64, count),
+        Binning::Sturges => (min, (max - min) / sturges() as f64, sturges()),
+        Binning::FreedmanDiaconis => {
+            let iqr = quantile(&finite, 0.75) - quantile(&finite, 0.25);
+            let width = 2f64 * iqr / (finite.len() as f64).cbrt();
+            let count = (max - min) / width;
+            let count = if width > 0f64 && count <= MAX_BINS as f64 {
+                count.ceil() as usize
+            } else {
+                sturges()
+            };
+            (min, (max - min) / count as f64, count)
+        }
+    };
+
+    let mut bins: Vec<Bin> = (0..count)
+        .map(|i| Bin {
+            start: start + i as f64 * width,
+            end: start + (i + 1) as f64 * width,
+            count: 0,
+        })
+        .collect();
+    for value in finite {
+        let i = ((value - start) / width).floor() as usize;
+        bins[i.min(count - 1)].count += 1;
+    }
+    Ok(bins)
+}
+
+/// Plots the distribution of `values` as bars, binned with [`Config::with_binning`].
+///
+/// The X axis labels the bin edges, formatted with [`Config::with_x_label_formatter`] when set.
+/// The counts on the Y axis have no decimals unless [`Config::with_precision`] asks for them.
+///
+/// # Panics
+///
+/// Panics on input [`try_histogram`] rejects with a [`PlotError`], except for empty input and input
+/// without finite values, which plot as an empty string.
+pub fn histogram(values: &[f64], config: Config) -> String {
+    crate::unwrap_plot(try_histogram(values, config))
+}
+
+/// Fallible version of [`histogram`].
+pub fn try_histogram(values: &[f64], mut config: Config) -> Result<String, PlotError> {
+    let canvas = draw_histogram(values, &mut config)?;
+    Ok(crate::render_plain(&canvas))
+}
+
+/// Plots a histogram colored with the first series color, see [`histogram`].
+///
+/// # Panics
+///
+/// Panics on input [`try_histogram_colored`] rejects with a [`PlotError`], except for empty input
+/// and input without finite values, which plot as an empty string.
+#[cfg(feature = "color")]
+pub fn histogram_colored(values: &[f64], config: Config) -> ColoredString {
+    crate::unwrap_plot(try_histogram_colored(values, config))
+}
+
+/// Fallible version of [`histogram_colored`].
+#[cfg(feature = "color")]
+pub fn try_histogram_colored(
+    values: &[f64],
+    mut config: Config,
+) -> Result<ColoredString, PlotError> {
+    crate::check_series_colors(1, &config)?;
+    let canvas = draw_histogram(values, &mut config)?;
+    Ok(crate::render_colored(&canvas, &config))
+}
+
+fn draw_histogram(values: &[f64], config: &mut Config) -> Result<Canvas, PlotError> {
+    // counts are whole numbers
+    if config.precision.is_none() {
+        config.precision = Some(0);
+    }
+    let bins = bins(values, config.binning)?;
+    let counts: Vec<f64> = bins.iter().map(|b| b.count as f64).collect();
+    let (first, last) = (bins[0].start, bins[bins.len() - 1].end);
+    let precision = label_precision(first, last);
+    let edges: Vec<String> = std::iter::once(first)
+        .chain(bins.iter().map(|b| b.end))
+        .map(|edge| match &config.x_label_formatter {
+            Some(formatter) => formatter(edge),
+            None => format!("{:.*}", precision, edge),
+        })
+        .collect();
+    let (mut canvas, left_pad, bar_width) = draw_bar_columns(&counts, config, &edges)?;
+
+    // edges sit in the gaps between bars, the first one on the Y axis
+    let axis_row = canvas.push_row();
+    let label_row = canvas.push_row();
+    let len = bins.len() * (bar_width + 1) - 1;
+    canvas.put_str(axis_row, left_pad - 1, "└", Style::Axis);
+    for column in 0..=len {
+        let tick = if (column + 1) % (bar_width + 1) == 0 {
+            "┬"
+        } else {
+            "─"
+        };
+        canvas.put_str(axis_row, left_pad + column, tick, Style::Axis);
+    }
+    let mut free = 0;
+    for (i, edge) in edges.iter().enumerate() {
+        let edge_len = edge.chars().count();
+        let start = (left_pad - 1 + i * (bar_width + 1)).saturating_sub(edge_len / 2);
+        if start < free {
+            continue;
+        }
+        canvas.put_str(label_row, start, edge, Style::Label);
+        free = start + edge_len + 1;
+    }
+    draw_caption(&mut canvas, config, left_pad, len);
+    Ok(canvas)
+}
+
+/// Linearly interpolated quantile `q` of the sorted `values`.
+fn quantile(values: &[f64], q: f64) -> f64 {
+    let position = q * (values.len() - 1) as f64;
+    let (below, above) = (position.floor() as usize, position.ceil() as usize);
+    values[below] + (values[above] - values[below]) * (position - below as f64)
+}
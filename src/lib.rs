@@ -1,5 +1,64 @@
 use std::vec::Vec;
 
+mod aggregation;
+mod annotations;
+mod area;
+mod bars;
+mod candles;
+mod canvas;
+mod error;
+pub mod formatters;
+mod histogram;
+mod live;
+mod scatter;
+mod sparkline;
+mod style;
+#[cfg(feature = "chrono")]
+mod time_series;
+
+use aggregation::{aggregate, draw_envelopes, Envelope};
+use annotations::{draw_annotation_markers, draw_annotations, Annotation};
+use area::{draw_areas, stack_series};
+pub use bars::{plot_bars, plot_hbar, try_plot_bars, try_plot_hbar};
+#[cfg(feature = "color")]
+pub use bars::{
+    plot_bars_colored, plot_hbar_colored, try_plot_bars_colored, try_plot_hbar_colored,
+};
+pub use candles::{plot_candles, try_plot_candles, Ohlc};
+#[cfg(feature = "color")]
+pub use candles::{plot_candles_colored, try_plot_candles_colored};
+use canvas::{Canvas, Style};
+pub use error::PlotError;
+pub use histogram::{bins, histogram, try_histogram, Bin, Binning, MAX_BINS};
+#[cfg(feature = "color")]
+pub use histogram::{histogram_colored, try_histogram_colored};
+pub use live::LivePlot;
+pub use scatter::{plot_scatter, plot_scatter_many, try_plot_scatter, try_plot_scatter_many};
+#[cfg(feature = "color")]
+pub use scatter::{
+    plot_scatter_colored, plot_scatter_many_colored, try_plot_scatter_colored,
+    try_plot_scatter_many_colored,
+};
+pub use sparkline::{sparkline, sparkline_with_summary};
+use style::Glyphs;
+pub use style::{
+    Aggregation, AnnotationPosition, AreaMode, BarOrder, Interpolation, LegendPosition, Marker,
+    Scale, SeriesStyle,
+};
+#[cfg(feature = "chrono")]
+pub use time_series::{
+    plot_time_series, plot_time_series_many, try_plot_time_series, try_plot_time_series_many,
+};
+
+type ValueFormatter = Box<dyn Fn(f64) -> String + Send + Sync>;
+
+/// Horizontal reference line, see [`Config::with_hline`].
+struct HLine {
+    value: f64,
+    label: String,
+    style: SeriesStyle,
+}
+
 #[cfg(feature = "color")]
 use colored::Color;
 #[cfg(feature = "color")]
@@ -12,6 +71,28 @@ pub struct Config {
     height: u32,
     offset: u32,
     caption: String,
+    lower_bound: Option<f64>,
+    upper_bound: Option<f64>,
+    strict_bounds: bool,
+    precision: Option<usize>,
+    value_formatter: Option<ValueFormatter>,
+    x_axis: bool,
+    x_tick_spacing: u32,
+    x_labels: Vec<String>,
+    x_label_formatter: Option<ValueFormatter>,
+    bar_values: bool,
+    bar_order: BarOrder,
+    braille: bool,
+    markers: Vec<Marker>,
+    binning: Binning,
+    y_scale: Scale,
+    hlines: Vec<HLine>,
+    annotations: Vec<Annotation>,
+    secondary_axis: Vec<usize>,
+    area: AreaMode,
+    interpolation: Interpolation,
+    aggregation: Aggregation,
+    annotation_position: AnnotationPosition,
     #[cfg(feature = "color")]
     caption_color: Color,
     #[cfg(feature = "color")]
@@ -20,8 +101,9 @@ pub struct Config {
     label_color: Color,
     #[cfg(feature = "color")]
     series_colors: Vec<Color>,
-    #[cfg(feature = "color")]
     series_legends: Vec<String>,
+    series_styles: Vec<SeriesStyle>,
+    legend_position: LegendPosition,
 }
 
 impl Default for Config {
@@ -31,6 +113,28 @@ impl Default for Config {
             height: 0,
             offset: 0,
             caption: String::new(),
+            lower_bound: None,
+            upper_bound: None,
+            strict_bounds: false,
+            precision: None,
+            value_formatter: None,
+            x_axis: false,
+            x_tick_spacing: 10,
+            x_labels: Vec::new(),
+            x_label_formatter: None,
+            bar_values: false,
+            bar_order: BarOrder::Input,
+            braille: false,
+            markers: Vec::new(),
+            binning: Binning::Sturges,
+            y_scale: Scale::Linear,
+            hlines: Vec::new(),
+            annotations: Vec::new(),
+            secondary_axis: Vec::new(),
+            area: AreaMode::Lines,
+            interpolation: Interpolation::Linear,
+            aggregation: Aggregation::Interpolate,
+            annotation_position: AnnotationPosition::Above,
             #[cfg(feature = "color")]
             caption_color: Color::White,
             #[cfg(feature = "color")]
@@ -39,8 +143,9 @@ impl Default for Config {
             label_color: Color::White,
             #[cfg(feature = "color")]
             series_colors: vec![],
-            #[cfg(feature = "color")]
             series_legends: Vec::new(),
+            series_styles: Vec::new(),
+            legend_position: LegendPosition::Below,
         }
     }
 }
@@ -51,6 +156,7 @@ impl Config {
         self
     }
 
+    /// Rows above the bottom one, 0 picks them from the range of the values, at most 100.
     pub fn with_height(mut self, height: u32) -> Self {
         self.height = height;
         self
@@ -66,6 +172,161 @@ impl Config {
         self
     }
 
+    /// Extends the vertical range down to at least `lower_bound`.
+    pub fn with_lower_bound(mut self, lower_bound: f64) -> Self {
+        self.lower_bound = Some(lower_bound);
+        self
+    }
+
+    /// Extends the vertical range up to at least `upper_bound`.
+    pub fn with_upper_bound(mut self, upper_bound: f64) -> Self {
+        self.upper_bound = Some(upper_bound);
+        self
+    }
+
+    /// Uses the configured bounds as the exact vertical range instead of extending it.
+    /// Samples outside of the range are drawn as `▲`/`▼` markers on the edge rows.
+    pub fn with_strict_bounds(mut self, strict_bounds: bool) -> Self {
+        self.strict_bounds = strict_bounds;
+        self
+    }
+
+    /// Uses a fixed number of decimals for Y-axis labels instead of deriving it from the data.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Formats Y-axis labels with `formatter`, see [`formatters`] for the built-in ones.
+    /// Takes precedence over [`Config::with_precision`].
+    pub fn with_value_formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(f64) -> String + Send + Sync + 'static,
+    {
+        self.value_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Draws an X axis below the plot, labeling ticks with sample indices by default.
+    pub fn with_x_axis(mut self, x_axis: bool) -> Self {
+        self.x_axis = x_axis;
+        self
+    }
+
+    /// Places an X-axis tick every `spacing` columns. Implies [`Config::with_x_axis`].
+    pub fn with_x_tick_spacing(mut self, spacing: u32) -> Self {
+        self.x_axis = true;
+        self.x_tick_spacing = spacing;
+        self
+    }
+
+    /// Labels X-axis ticks with `labels`, one per sample. Implies [`Config::with_x_axis`].
+    pub fn with_x_labels(mut self, labels: Vec<String>) -> Self {
+        self.x_axis = true;
+        self.x_labels = labels;
+        self
+    }
+
+    /// Labels X-axis ticks by formatting the sample position under the tick, which is fractional
+    /// when [`Config::with_width`] stretches the series. Implies [`Config::with_x_axis`].
+    pub fn with_x_label_formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(f64) -> String + Send + Sync + 'static,
+    {
+        self.x_axis = true;
+        self.x_label_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Prints each bar's value next to its end in bar charts.
+    pub fn with_bar_values(mut self, bar_values: bool) -> Self {
+        self.bar_values = bar_values;
+        self
+    }
+
+    /// Maps line and scatter plot values onto the Y axis with `scale`, labeling logarithmic
+    /// scales at powers of their base where the range spans several of them.
+    pub fn with_y_scale(mut self, scale: Scale) -> Self {
+        self.y_scale = scale;
+        self
+    }
+
+    /// Draws a horizontal reference line at `value` beneath the series of line, scatter and bar
+    /// plots, labeled on the right margin. The Y range grows to include it unless bounds are
+    /// strict.
+    pub fn with_hline(mut self, value: f64, label: String, style: SeriesStyle) -> Self {
+        self.hlines.push(HLine {
+            value,
+            label,
+            style,
+        });
+        self
+    }
+
+    /// Marks sample `x` of line plots with a `┊` column and prints `text` above the plot.
+    pub fn with_annotation(mut self, x: usize, text: String) -> Self {
+        self.annotations.push(Annotation { x, text });
+        self
+    }
+
+    pub fn with_annotation_position(mut self, position: AnnotationPosition) -> Self {
+        self.annotation_position = position;
+        self
+    }
+
+    /// Scales the line plot series at the given indices to their own range, labeled on a
+    /// secondary Y axis right of the plot. Legends then name the axis of each series.
+    pub fn with_secondary_axis(mut self, series: Vec<usize>) -> Self {
+        self.secondary_axis = series;
+        self
+    }
+
+    /// Shades the area below line plot series, or stacks them, see [`AreaMode`]. Legends then
+    /// show each series' shade.
+    pub fn with_area(mut self, area: AreaMode) -> Self {
+        self.area = area;
+        self
+    }
+
+    /// How series are resampled to the configured width, see [`Interpolation`]. Step series are
+    /// drawn holding each value until the next sample.
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// How series longer than the configured width are downsampled, see [`Aggregation`].
+    pub fn with_aggregation(mut self, aggregation: Aggregation) -> Self {
+        self.aggregation = aggregation;
+        self
+    }
+
+    /// Draws lines with Braille dots, each cell holding 2×4 dots. The configured width counts
+    /// cells, so a plot holds twice as many samples and four times as many levels.
+    pub fn with_braille(mut self, braille: bool) -> Self {
+        self.braille = braille;
+        self
+    }
+
+    /// Marks the points of each scatter plot series with its marker, series without one use
+    /// [`Marker::Dot`].
+    pub fn with_markers(mut self, markers: Vec<Marker>) -> Self {
+        self.markers = markers;
+        self
+    }
+
+    /// Chooses how histograms bin their values, [`Binning::Sturges`] by default.
+    pub fn with_binning(mut self, binning: Binning) -> Self {
+        self.binning = binning;
+        self
+    }
+
+    /// Sorts the items of horizontal bar charts.
+    pub fn with_bar_order(mut self, bar_order: BarOrder) -> Self {
+        self.bar_order = bar_order;
+        self
+    }
+
     #[cfg(feature = "color")]
     pub fn with_caption_color(mut self, color: Color) -> Self {
         self.caption_color = color;
@@ -90,320 +351,948 @@ impl Config {
         self
     }
 
-    #[cfg(feature = "color")]
     pub fn with_series_legends(mut self, legends: Vec<String>) -> Self {
         self.series_legends = legends;
         self
     }
+
+    /// Draws each series with its own glyph set, series without a style are drawn
+    /// [`SeriesStyle::Solid`]. Legends then show the series' glyph instead of `■`.
+    pub fn with_series_styles(mut self, styles: Vec<SeriesStyle>) -> Self {
+        self.series_styles = styles;
+        self
+    }
+
+    pub fn with_legend_position(mut self, position: LegendPosition) -> Self {
+        self.legend_position = position;
+        self
+    }
 }
 
 pub fn plot(series: Vec<f64>, config: Config) -> String {
     plot_many(vec![series], config)
 }
 
-pub fn plot_many(mut series: Vec<Vec<f64>>, mut config: Config) -> String {
-    let mut len_max = series.iter().map(|s| s.len()).max().unwrap_or(0);
-    if config.width > 0 {
-        series.iter_mut().for_each(|s| {
-            if s.len() < len_max {
-                s.extend(vec![f64::NAN].repeat(len_max - s.len()))
-            }
-            *s = interpolate(s, config.width);
-        });
-        len_max = config.width as usize;
-    }
+/// Plots multiple series on the same graph. Missing (NaN) and infinite samples are left as gaps.
+///
+/// # Panics
+///
+/// Panics on input [`try_plot_many`] rejects with a [`PlotError`], except for empty input and input
+/// without finite values, which plot as an empty string.
+pub fn plot_many(series: Vec<Vec<f64>>, config: Config) -> String {
+    unwrap_plot(try_plot_many(series, config))
+}
 
-    let mut min = f64::MAX;
-    let mut max = f64::MIN;
+/// Fallible version of [`plot`].
+pub fn try_plot(series: Vec<f64>, config: Config) -> Result<String, PlotError> {
+    try_plot_many(vec![series], config)
+}
 
-    (min, max) = series.iter().map(|s| min_max(s)).fold(
-        (min, max),
-        |(current_min, current_max), (next_min, next_max)| {
-            (
-                f64::min(next_min, current_min),
-                f64::max(next_max, current_max),
-            )
-        },
-    );
+/// Fallible version of [`plot_many`].
+pub fn try_plot_many(series: Vec<Vec<f64>>, config: Config) -> Result<String, PlotError> {
+    let canvas = draw_lines(series, &config)?;
+    Ok(render_plain(&canvas))
+}
 
-    let interval = (max - min).abs();
-    if config.height == 0 {
-        if interval == 0f64 {
-            config.height = 3;
-        } else if interval <= 1f64 {
-            config.height =
-                (interval * f64::from(10i32.pow((-interval.log10()).ceil() as u32))) as u32;
-        } else {
-            config.height = interval as u32;
-        }
+#[cfg(feature = "color")]
+pub fn plot_colored(series: Vec<f64>, config: Config) -> ColoredString {
+    plot_many_colored(vec![series], config)
+}
+
+/// Plots multiple series on the same graph using `config` colors, see [`plot_many`].
+///
+/// # Panics
+///
+/// Panics on input [`try_plot_many_colored`] rejects with a [`PlotError`], except for empty input
+/// and input without finite values, which plot as an empty string.
+#[cfg(feature = "color")]
+pub fn plot_many_colored(series: Vec<Vec<f64>>, config: Config) -> ColoredString {
+    unwrap_plot(try_plot_many_colored(series, config))
+}
+
+/// Fallible version of [`plot_colored`].
+#[cfg(feature = "color")]
+pub fn try_plot_colored(series: Vec<f64>, config: Config) -> Result<ColoredString, PlotError> {
+    try_plot_many_colored(vec![series], config)
+}
+
+/// Fallible version of [`plot_many_colored`].
+#[cfg(feature = "color")]
+pub fn try_plot_many_colored(
+    series: Vec<Vec<f64>>,
+    config: Config,
+) -> Result<ColoredString, PlotError> {
+    check_series_colors(series.len(), &config)?;
+    let canvas = draw_lines(series, &config)?;
+    Ok(render_colored(&canvas, &config))
+}
+
+/// Result of the infallible version of a plot: empty input or input without finite values makes
+/// an empty plot, any other error panics.
+fn unwrap_plot<T: Default>(result: Result<T, PlotError>) -> T {
+    match result {
+        Err(PlotError::EmptySeries { .. }) | Err(PlotError::NoFiniteValues) => T::default(),
+        result => result.unwrap_or_else(|err| panic!("{}", err)),
     }
+}
+
+fn render_plain(canvas: &Canvas) -> String {
+    canvas.render(|text, _| text.to_string())
+}
 
-    if config.offset == 0 {
-        config.offset = 3;
+#[cfg(feature = "color")]
+fn render_colored(canvas: &Canvas, config: &Config) -> ColoredString {
+    canvas
+        .render(|text, style| paint(text, style, config))
+        .into()
+}
+
+#[cfg(feature = "color")]
+fn check_series_colors(series: usize, config: &Config) -> Result<(), PlotError> {
+    let required = usize::max(series, config.series_legends.len());
+    if config.series_colors.len() < required {
+        return Err(PlotError::MissingSeriesColors {
+            required,
+            provided: config.series_colors.len(),
+        });
     }
+    Ok(())
+}
 
-    let ratio = if interval != 0f64 {
-        f64::from(config.height) / interval
-    } else {
-        1f64
+/// Colors a run of canvas cells according to `config`.
+#[cfg(feature = "color")]
+fn paint(text: &str, style: Style, config: &Config) -> String {
+    let color = match style {
+        Style::Default => return text.to_string(),
+        Style::Axis => config.axis_color,
+        Style::Label => config.label_color,
+        Style::Caption => config.caption_color,
+        Style::Series(i) => config.series_colors[i],
     };
+    text.color(color).to_string()
+}
+
+/// Draws `series` as lines along with the axes, caption and legends `config` asks for.
+fn draw_lines(series: Vec<Vec<f64>>, config: &Config) -> Result<Canvas, PlotError> {
+    let lines = prepare_lines(series, config)?;
+    let mut canvas = Canvas::default();
+    draw_prepared(&mut canvas, lines, config, |canvas, axes| {
+        let (axes, left_pad) = draw_axes(axes, config);
+        *canvas = axes;
+        left_pad
+    });
+    Ok(canvas)
+}
+
+/// Left axis layout and secondary axis range of a line plot.
+type Axes = (Layout, Option<(f64, f64)>);
 
-    let min2 = (min * ratio).round();
-    let max2 = (max * ratio).round();
+/// Line plot series checked, scaled, fitted to the width and laid out on the axes.
+struct Lines {
+    series: Vec<Vec<f64>>,
+    envelopes: Vec<Envelope>,
+    axes: Axes,
+}
 
-    let int_min2 = min2 as i32;
-    let int_max2 = max2 as i32;
+fn prepare_lines(mut series: Vec<Vec<f64>>, config: &Config) -> Result<Lines, PlotError> {
+    check_input(&series, config)?;
+    drop_infinite(&mut series);
+    if config.area == AreaMode::Stacked {
+        stack_series(&mut series);
+    }
+    scale_series(&mut series, config.y_scale)?;
+    let (samples, mut envelopes) = fit_width(&mut series, config);
+    let axes = layout_axes(&mut series, &mut envelopes, samples, config)?;
+    Ok(Lines {
+        series,
+        envelopes,
+        axes,
+    })
+}
 
-    let rows = f64::from(int_max2 - int_min2).abs() as i32;
-    let width = len_max as u32 + config.offset;
+/// Draws prepared lines onto `canvas` once `draw_axes` has drawn their axes into it and returned
+/// the column the plot starts at.
+fn draw_prepared<A>(canvas: &mut Canvas, mut lines: Lines, config: &Config, draw_axes: A)
+where
+    A: FnOnce(&mut Canvas, &Axes) -> usize,
+{
+    let left_pad = draw_axes(canvas, &lines.axes);
+    let layout = &lines.axes.0;
+    draw_series(
+        canvas,
+        &mut lines.series,
+        &lines.envelopes,
+        layout,
+        left_pad,
+        config,
+    );
+    draw_decorations(canvas, &lines.series, layout, left_pad, config);
+}
 
-    let mut plot: Vec<Vec<String>> = Vec::new();
+/// Draws the left axis, and the secondary one when some series are on it.
+fn draw_axes((layout, secondary): &Axes, config: &Config) -> (Canvas, usize) {
+    let (mut canvas, left_pad) = draw_y_axis(layout, config);
+    if let Some(range) = *secondary {
+        draw_secondary_axis(&mut canvas, layout, range, left_pad, config);
+    }
+    (canvas, left_pad)
+}
 
-    for _i in 0..=rows {
-        let mut line = Vec::<String>::new();
-        for _j in 0..width {
-            line.push(" ".to_string());
+/// Maps every value onto `scale`, rejecting values a logarithmic scale has no place for.
+fn scale_series(series: &mut [Vec<f64>], scale: Scale) -> Result<(), PlotError> {
+    if scale == Scale::Linear {
+        return Ok(());
+    }
+    for value in series.iter_mut().flatten() {
+        let scaled = scale.forward(*value);
+        if scaled.is_nan() && !value.is_nan() {
+            return Err(PlotError::NonPositiveValue(*value));
         }
-        plot.push(line);
+        *value = scaled;
     }
+    Ok(())
+}
 
-    let mut precision = 2;
-    let log_maximum = if min == 0f64 && max == 0f64 {
-        -1f64
-    } else {
-        f64::max(max.abs(), min.abs()).log10()
+/// Lays out the series and their envelopes on the left axis, returning the range of the series on
+/// the secondary axis if any. Those are mapped onto the range of the left axis so they are drawn
+/// like the others.
+fn layout_axes(
+    series: &mut [Vec<f64>],
+    envelopes: &mut [Envelope],
+    samples: usize,
+    config: &Config,
+) -> Result<(Layout, Option<(f64, f64)>), PlotError> {
+    let secondary = secondary_series(config, series);
+    // envelopes follow their series, one per series when there are any
+    let bounds = |i: usize| {
+        let mut bounds = vec![series[i].clone()];
+        if let Some((lows, highs)) = envelopes.get(i) {
+            bounds.extend([lows.clone(), highs.clone()]);
+        }
+        bounds
     };
+    let primary: Vec<Vec<f64>> = (0..series.len())
+        .filter(|i| !secondary.contains(i))
+        .flat_map(bounds)
+        .collect();
+    let layout = Layout::new(&primary, samples, config.y_scale, config)?;
+    if secondary.is_empty() {
+        return Ok((layout, None));
+    }
 
-    if log_maximum < 0f64 {
-        if log_maximum % 1f64 != 0f64 {
-            precision += log_maximum.abs() as i32;
+    let (min, max) = secondary
+        .iter()
+        .flat_map(|&i| bounds(i))
+        .map(|s| min_max(&s))
+        .fold((f64::MAX, f64::MIN), |(min, max), (next_min, next_max)| {
+            (f64::min(min, next_min), f64::max(max, next_max))
+        });
+    if min > max {
+        return Ok((layout, None));
+    }
+
+    let map = |value: &mut f64| {
+        *value = if max > min {
+            layout.min + (*value - min) / (max - min) * (layout.max - layout.min)
         } else {
-            precision += (log_maximum.abs() - 1f64) as i32;
+            // a flat series sits in the middle of the plot
+            (layout.min + layout.max) / 2f64
+        };
+    };
+    for &i in &secondary {
+        series[i].iter_mut().for_each(map);
+        if let Some((lows, highs)) = envelopes.get_mut(i) {
+            lows.iter_mut().chain(highs.iter_mut()).for_each(map);
         }
-    } else if log_maximum > 2f64 {
-        precision = 0;
     }
+    Ok((layout, Some((min, max))))
+}
+
+/// Draws the secondary axis right of the plot, labeling the rows of `layout` with values of
+/// `min..=max`.
+fn draw_secondary_axis(
+    canvas: &mut Canvas,
+    layout: &Layout,
+    (min, max): (f64, f64),
+    left_pad: usize,
+    config: &Config,
+) {
+    let scale = layout.scale;
+    let (real_min, real_max) = (scale.inverse(min), scale.inverse(max));
+    let format_value = value_formatter(config, real_min, real_max);
+    let rows = layout.rows;
+    let labels: Vec<String> = (0..=rows)
+        .map(|row| {
+            let value = if rows > 0 {
+                max - f64::from(row) * (max - min) / f64::from(rows)
+            } else {
+                max
+            };
+            format_value(scale.inverse(value))
+        })
+        .collect();
+    let width = labels
+        .iter()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or(0);
+    let col = left_pad + layout.len_max;
+    for (row, label) in labels.iter().enumerate() {
+        canvas.put_str(row, col, "├", Style::Axis);
+        canvas.put_str(
+            row,
+            col + 1,
+            &format!(" {:>W$}", label, W = width),
+            Style::Label,
+        );
+    }
+}
 
-    let max_number_label_length = format!("{:.*}", precision as usize, max).len();
-    let min_number_label_length = format!("{:.*}", precision as usize, min).len();
+/// Pads and interpolates every series to the configured width, or aggregates it when longer,
+/// returning the original length and the envelopes of [`Aggregation::MinMax`].
+fn fit_width(series: &mut [Vec<f64>], config: &Config) -> (usize, Vec<Envelope>) {
+    let len_max = series.iter().map(|s| s.len()).max().unwrap_or(0);
+    let mut envelopes = Vec::new();
+    if config.width > 0 {
+        // Braille cells hold two samples side by side
+        let columns = if config.braille {
+            config.width * 2
+        } else {
+            config.width
+        };
+        let aggregated =
+            config.aggregation != Aggregation::Interpolate && (columns as usize) < len_max;
+        series.iter_mut().for_each(|s| {
+            if s.len() < len_max {
+                s.extend([f64::NAN].repeat(len_max - s.len()))
+            }
+            if aggregated {
+                let (values, envelope) = aggregate(s, columns as usize, config.aggregation);
+                *s = values;
+                envelopes.extend(envelope);
+            } else {
+                *s = interpolate(s, columns, config.interpolation);
+            }
+        });
+    }
+    (len_max, envelopes)
+}
 
-    let max_label_width = usize::max(max_number_label_length, min_number_label_length);
+/// Most rows a plot without [`Config::with_height`] gets.
+const MAX_AUTO_HEIGHT: u32 = 100;
+
+/// Vertical scale and size of a line plot, shared by its drawing stages.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Layout {
+    min: f64,
+    max: f64,
+    ratio: f64,
+    min2: f64,
+    int_min2: i32,
+    int_max2: i32,
+    rows: i32,
+    offset: u32,
+    len_max: usize,
+    samples: usize,
+    scale: Scale,
+}
 
-    for y in int_min2..=int_max2 {
-        let magnitude = if rows > 0 {
-            max - f64::from(y - int_min2) * interval / f64::from(rows)
+impl Layout {
+    /// Lays out `series` already mapped onto `scale`.
+    fn new(
+        series: &[Vec<f64>],
+        samples: usize,
+        scale: Scale,
+        config: &Config,
+    ) -> Result<Self, PlotError> {
+        let len_max = series.iter().map(|s| s.len()).max().unwrap_or(0);
+        let len_max = if config.braille {
+            len_max.div_ceil(2)
         } else {
-            f64::from(y)
+            len_max
         };
-        let label = format!(
-            "{number:LW$.PREC$}",
-            LW = max_label_width + 1,
-            PREC = precision as usize,
-            number = magnitude
+        let (min, max) = series.iter().map(|s| min_max(s)).fold(
+            (f64::MAX, f64::MIN),
+            |(current_min, current_max), (next_min, next_max)| {
+                (
+                    f64::min(next_min, current_min),
+                    f64::max(next_max, current_max),
+                )
+            },
         );
-        let w = (y - int_min2) as usize;
-        let h = f64::max(f64::from(config.offset) - label.len() as f64, 0f64) as usize;
-        plot[w][h] = label;
-        plot[w][(config.offset - 1) as usize] = "┤".to_string();
-    }
-
-    for series_inner in series {
-        let mut y0;
-        let mut y1;
-        if !series_inner[0].is_nan() {
-            y0 = ((series_inner[0] * ratio).round() - min2) as i32;
-            plot[(rows - y0) as usize][(config.offset - 1) as usize] = "┼".to_string();
+        // reference lines are always in view
+        let (min, max) = config
+            .hlines
+            .iter()
+            .map(|hline| scale.forward(hline.value))
+            .filter(|value| value.is_finite())
+            .fold((min, max), |(min, max), value| {
+                (f64::min(min, value), f64::max(max, value))
+            });
+        let (min, max) = bounded_range(min, max, scale, config)?;
+
+        let interval = (max - min).abs();
+        let height = if config.height > 0 {
+            config.height
+        } else if interval == 0f64 {
+            3
+        } else {
+            let rows = if interval <= 1f64 {
+                // scaled in f64, tiny ranges need powers of ten beyond any integer
+                interval * 10f64.powi((-interval.log10()).ceil() as i32)
+            } else {
+                interval
+            };
+            // far reference lines or outliers would otherwise make a grid too large to draw
+            (rows as u32).clamp(1, MAX_AUTO_HEIGHT)
+        };
+        let offset = if config.offset > 0 { config.offset } else { 3 };
+
+        // ranges too small to scale up to the height are drawn flat
+        let ratio = match f64::from(height) / interval {
+            ratio if ratio.is_finite() => ratio,
+            _ => 1f64,
+        };
+
+        let min2 = (min * ratio).round();
+        let max2 = (max * ratio).round();
+
+        let int_min2 = min2 as i32;
+        let int_max2 = max2 as i32;
+
+        let rows = f64::from(int_max2 - int_min2).abs() as i32;
+        Ok(Layout {
+            min,
+            max,
+            ratio,
+            min2,
+            int_min2,
+            int_max2,
+            rows,
+            offset,
+            len_max,
+            samples,
+            scale,
+        })
+    }
+
+    /// Scaled height of `value` above the bottom row.
+    fn scaled(&self, value: f64) -> i32 {
+        ((value * self.ratio).round() - self.min2) as i32
+    }
+
+    /// Canvas row of a scaled height.
+    fn at(&self, y: i32) -> usize {
+        (self.rows - y) as usize
+    }
+}
+
+/// Creates the canvas for `layout` holding the Y-axis labels, returning it with the column
+/// the plot area starts at.
+fn draw_y_axis(layout: &Layout, config: &Config) -> (Canvas, usize) {
+    let (labels, max_label_width) = y_labels(layout, config);
+    let left_pad = layout.offset as usize + max_label_width;
+    let mut canvas = Canvas::new(left_pad + layout.len_max, layout.rows as usize + 1);
+
+    for (w, text) in labels.iter().enumerate() {
+        let label = format!("{:>LW$}", text, LW = max_label_width + 1);
+        let h = (layout.offset as usize).saturating_sub(label.chars().count());
+        canvas.put_str(w, h, &label, Style::Label);
+        canvas.set(w, left_pad - 1, '┤', Style::Axis);
+    }
+    (canvas, left_pad)
+}
+
+fn draw_series(
+    canvas: &mut Canvas,
+    series: &mut [Vec<f64>],
+    envelopes: &[Envelope],
+    layout: &Layout,
+    left_pad: usize,
+    config: &Config,
+) {
+    draw_annotation_markers(canvas, layout, left_pad, config);
+    draw_hlines(canvas, layout, left_pad, config);
+    draw_areas(canvas, series, layout, left_pad, config);
+    if config.braille {
+        draw_braille_series(canvas, series, envelopes, layout, left_pad, config);
+        return;
+    }
+    draw_envelopes(canvas, envelopes, layout, left_pad, config);
+    let clipped = if config.strict_bounds {
+        clip_series(series, layout.min, layout.max)
+    } else {
+        Vec::new()
+    };
+
+    let scaled = |value: f64| layout.scaled(value);
+    let at = |y: i32| layout.at(y);
+    for (i, series_inner) in series.iter().enumerate() {
+        let style = Style::Series(i);
+        let glyphs = series_glyphs(config, i);
+        if !series_inner[0].is_nan() && !on_secondary_axis(config, series, i) {
+            canvas.set(at(scaled(series_inner[0])), left_pad - 1, '┼', Style::Axis);
         }
 
         for x in 0..series_inner.len() - 1 {
+            let col = left_pad + x;
             if series_inner[x].is_nan() && series_inner[x + 1].is_nan() {
                 continue;
             }
             if series_inner[x + 1].is_nan() && !series_inner[x].is_nan() {
-                y0 = ((series_inner[x] * ratio).round() - f64::from(int_min2)) as i32;
-                plot[(rows - y0) as usize][(x as u32 + config.offset) as usize] = "─".to_string();
+                canvas.set(at(scaled(series_inner[x])), col, glyphs.horizontal, style);
                 continue;
             }
             if series_inner[x].is_nan() && !series_inner[x + 1].is_nan() {
-                y1 = ((series_inner[x + 1] * ratio).round() - f64::from(int_min2)) as i32;
-                plot[(rows - y1) as usize][(x as u32 + config.offset) as usize] = "─".to_string();
+                canvas.set(
+                    at(scaled(series_inner[x + 1])),
+                    col,
+                    glyphs.horizontal,
+                    style,
+                );
                 continue;
             }
-            y0 = ((series_inner[x] * ratio).round() - f64::from(int_min2)) as i32;
-            y1 = ((series_inner[x + 1] * ratio).round() - f64::from(int_min2)) as i32;
+            let y0 = scaled(series_inner[x]);
+            let y1 = scaled(series_inner[x + 1]);
 
             if y0 == y1 {
-                plot[(rows - y0) as usize][(x as u32 + config.offset) as usize] = "─".to_string();
+                canvas.set(at(y0), col, glyphs.horizontal, style);
             } else {
                 if y0 > y1 {
-                    plot[(rows - y1) as usize][(x as u32 + config.offset) as usize] =
-                        "╰".to_string();
-                    plot[(rows - y0) as usize][(x as u32 + config.offset) as usize] =
-                        "╮".to_string();
+                    canvas.set(at(y1), col, glyphs.up_right, style);
+                    canvas.set(at(y0), col, glyphs.down_left, style);
                 } else {
-                    plot[(rows - y1) as usize][(x as u32 + config.offset) as usize] =
-                        "╭".to_string();
-                    plot[(rows - y0) as usize][(x as u32 + config.offset) as usize] =
-                        "╯".to_string();
+                    canvas.set(at(y1), col, glyphs.down_right, style);
+                    canvas.set(at(y0), col, glyphs.up_left, style);
                 }
 
-                let start = f64::min(f64::from(y0), f64::from(y1)) as i32 + 1;
-                let end = f64::max(f64::from(y0), f64::from(y1)) as i32;
-
-                for y in start..end {
-                    plot[(rows - y) as usize][(x as u32 + config.offset) as usize] =
-                        "│".to_string();
+                for y in i32::min(y0, y1) + 1..i32::max(y0, y1) {
+                    canvas.set(at(y), col, glyphs.vertical, style);
                 }
             }
         }
     }
 
-    let mut res: String = plot
-        .into_iter()
-        .map(|line| line.join(""))
-        .collect::<Vec<String>>()
-        .join("\n");
-    res.pop();
-    if !config.caption.is_empty() {
-        res.push('\n');
-        res.push_str(
-            std::iter::repeat(" ")
-                .take(config.offset as usize + max_label_width as usize)
-                .collect::<String>()
-                .as_ref(),
-        );
-        if config.caption.len() < len_max {
-            res.push_str(
-                std::iter::repeat(" ")
-                    .take((len_max - config.caption.len()) / 2)
-                    .collect::<String>()
-                    .as_ref(),
-            );
-        }
-        res.push_str(config.caption.as_ref());
+    for (i, x, above) in clipped {
+        let row = if above { 0 } else { layout.rows as usize };
+        canvas.set(row, left_pad + x, clip_marker(above), Style::Series(i));
     }
-    res
 }
 
-#[cfg(feature = "color")]
-fn create_legend_item(text: &str, color: colored::Color) -> (String, usize) {
-    use colored::Colorize;
-
-    let colored_box = "■".color(color).to_string(); // Colored box
-    let default_color = "".normal().to_string(); // Reset to default color
-    let legend_item = format!("{}{} {}", colored_box, default_color, text);
+/// Braille dot bits by column and row within a cell.
+const BRAILLE_DOTS: [[u8; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+/// Draws every sample as a Braille dot, two samples per column, joining neighbouring samples.
+fn draw_braille_series(
+    canvas: &mut Canvas,
+    series: &mut [Vec<f64>],
+    envelopes: &[Envelope],
+    layout: &Layout,
+    left_pad: usize,
+    config: &Config,
+) {
+    let clipped = if config.strict_bounds {
+        clip_series(series, layout.min, layout.max)
+    } else {
+        Vec::new()
+    };
 
-    // Calculate the length of the legend item (accounting for the box and space)
-    let legend_length = text.chars().count() + 2; // 2 for the box and space
+    let rows = layout.rows as usize + 1;
+    let dot_rows = rows as i64 * 4;
+    // dots counted from the top, a value at a row label sits in the middle of its cell
+    let dot_y = |value: f64| {
+        let height = value * layout.ratio - layout.min2;
+        dot_rows - 1 - ((height * 4f64 + 1.5).round() as i64).clamp(0, dot_rows - 1)
+    };
+    let mut dots = vec![vec![0u8; layout.len_max]; rows];
+    for (i, series_inner) in series.iter().enumerate() {
+        let style = Style::Series(i);
+        if !series_inner[0].is_nan() && !on_secondary_axis(config, series, i) {
+            let row = layout.at(layout.scaled(series_inner[0]));
+            canvas.set(row, left_pad - 1, '┼', Style::Axis);
+        }
+        let mut plot = |x: i64, y: i64| {
+            let (col, row) = (x as usize / 2, y as usize / 4);
+            dots[row][col] |= BRAILLE_DOTS[x as usize % 2][y as usize % 4];
+            let ch = char::from_u32(0x2800 + u32::from(dots[row][col])).unwrap_or(' ');
+            canvas.set(row, left_pad + col, ch, style);
+        };
+        if let Some((lows, highs)) = envelopes.get(i) {
+            for (x, (&low, &high)) in lows.iter().zip(highs).enumerate() {
+                if !low.is_nan() && !high.is_nan() {
+                    rasterize(x as i64, dot_y(low), x as i64, dot_y(high), &mut plot);
+                }
+            }
+        }
+        for (x, pair) in series_inner.windows(2).enumerate() {
+            let x = x as i64;
+            match (pair[0].is_nan(), pair[1].is_nan()) {
+                (false, false) if config.interpolation == Interpolation::Step => {
+                    let (y0, y1) = (dot_y(pair[0]), dot_y(pair[1]));
+                    rasterize(x, y0, x + 1, y0, &mut plot);
+                    rasterize(x + 1, y0, x + 1, y1, &mut plot);
+                }
+                (false, false) => rasterize(x, dot_y(pair[0]), x + 1, dot_y(pair[1]), &mut plot),
+                (false, true) => plot(x, dot_y(pair[0])),
+                _ => {}
+            }
+        }
+        let last = series_inner.len() - 1;
+        if !series_inner[last].is_nan() {
+            plot(last as i64, dot_y(series_inner[last]));
+        }
+    }
 
-    (legend_item, legend_length)
+    for (i, x, above) in clipped {
+        let row = if above { 0 } else { layout.rows as usize };
+        canvas.set(row, left_pad + x / 2, clip_marker(above), Style::Series(i));
+    }
 }
 
-#[cfg(feature = "color")]
-fn add_legends(lines: &mut String, config: &Config, len_max: usize, left_pad: usize) {
-    use std::iter;
-
-    lines.push_str("\n\n");
-    lines.push_str(&iter::repeat(" ").take(left_pad).collect::<String>());
+/// Calls `plot` for every point of the line from `(x0, y0)` to `(x1, y1)` (Bresenham).
+fn rasterize<F: FnMut(i64, i64)>(x0: i64, y0: i64, x1: i64, y1: i64, plot: &mut F) {
+    let (dx, dy) = ((x1 - x0).abs(), -(y1 - y0).abs());
+    let (sx, sy) = ((x1 - x0).signum(), (y1 - y0).signum());
+    let (mut x, mut y, mut err) = (x0, y0, dx + dy);
+    loop {
+        plot(x, y);
+        if x == x1 && y == y1 {
+            break;
+        }
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+}
 
-    let mut legends_text = String::new();
-    let mut legends_text_len = 0;
-    let right_pad = 3;
+/// Finishes a line plot with legends, the X axis and the caption.
+fn draw_decorations(
+    canvas: &mut Canvas,
+    series: &[Vec<f64>],
+    layout: &Layout,
+    left_pad: usize,
+    config: &Config,
+) {
+    let len_max = layout.len_max;
+    // the last sample has no segment leaving it, so the column is only drawn into by markers
+    canvas.trim_last(layout.rows as usize);
+
+    // right of the plot and of the secondary axis labels
+    let margin = (0..=layout.rows as usize)
+        .map(|row| canvas.row_len(row))
+        .max()
+        .unwrap_or(0)
+        .max(left_pad + len_max)
+        + 1;
+    let below = config.legend_position == LegendPosition::Below;
+    let right = secondary_series(config, series);
+    if !config.series_legends.is_empty() && !below {
+        let ends: Vec<Option<usize>> = series
+            .iter()
+            .map(|s| {
+                s.iter()
+                    .rev()
+                    .find(|v| !v.is_nan())
+                    .map(|v| layout.at(layout.scaled(*v)))
+            })
+            .collect();
+        add_side_legends(canvas, config, &ends, margin, &right, |i| {
+            legend_marker(config, i)
+        });
+    }
+    draw_hline_labels(canvas, layout, margin, config);
+    if config.x_axis {
+        draw_x_axis(canvas, len_max, layout.samples, left_pad, config);
+    }
+    draw_caption(canvas, config, left_pad, len_max);
+    if !config.series_legends.is_empty() && below {
+        add_legends(canvas, config, len_max, left_pad, &right, |i| {
+            legend_marker(config, i)
+        });
+    }
+    draw_annotations(canvas, layout, left_pad, config);
+}
 
-    for (i, text) in config.series_legends.iter().enumerate() {
-        let (item, item_len) = create_legend_item(text, config.series_colors[i]);
-        legends_text.push_str(&item);
-        legends_text_len += item_len;
+/// Draws the reference lines falling within the range of `layout`.
+fn draw_hlines(canvas: &mut Canvas, layout: &Layout, left_pad: usize, config: &Config) {
+    for hline in &config.hlines {
+        if let Some(row) = hline_row(layout, hline) {
+            let glyph = hline.style.glyphs().horizontal;
+            for col in left_pad..left_pad + layout.len_max {
+                canvas.set(row, col, glyph, Style::Axis);
+            }
+        }
+    }
+}
 
-        if i < config.series_legends.len() - 1 {
-            legends_text.push_str(&iter::repeat(" ").take(right_pad).collect::<String>());
-            legends_text_len += right_pad;
+/// Labels the reference lines from column `col` on, after anything already drawn right of it.
+fn draw_hline_labels(canvas: &mut Canvas, layout: &Layout, col: usize, config: &Config) {
+    for hline in config.hlines.iter().filter(|hline| !hline.label.is_empty()) {
+        if let Some(row) = hline_row(layout, hline) {
+            let taken = canvas.row_len(row);
+            let start = if taken >= col { taken + 2 } else { col };
+            canvas.put_str(row, start, &hline.label, Style::Label);
         }
     }
+}
 
-    if legends_text_len < len_max {
-        lines.push_str(
-            &iter::repeat(" ")
-                .take((len_max - legends_text_len) / 2)
-                .collect::<String>(),
-        );
+fn hline_row(layout: &Layout, hline: &HLine) -> Option<usize> {
+    let value = layout.scale.forward(hline.value);
+    if (layout.min..=layout.max).contains(&value) {
+        Some(layout.at(layout.scaled(value)))
+    } else {
+        None
     }
+}
 
-    lines.push_str(&legends_text);
+/// Appends the caption centered over the `width` columns starting at `left_pad`.
+fn draw_caption(canvas: &mut Canvas, config: &Config, left_pad: usize, width: usize) {
+    if !config.caption.is_empty() {
+        let row = canvas.push_row();
+        let caption_len = config.caption.chars().count();
+        let pad = width.saturating_sub(caption_len) / 2;
+        canvas.put_str(row, left_pad + pad, &config.caption, Style::Caption);
+    }
 }
 
-#[cfg(feature = "color")]
-pub fn plot_colored(series: Vec<f64>, config: Config) -> ColoredString {
-    plot_many_colored(vec![series], config)
+fn series_glyphs(config: &Config, series: usize) -> Glyphs {
+    config
+        .series_styles
+        .get(series)
+        .copied()
+        .unwrap_or(match config.interpolation {
+            Interpolation::Step => SeriesStyle::Step,
+            _ => SeriesStyle::Solid,
+        })
+        .glyphs()
 }
 
-#[cfg(feature = "color")]
-pub fn plot_many_colored(mut series: Vec<Vec<f64>>, mut config: Config) -> ColoredString {
-    let mut len_max = series.iter().map(|s| s.len()).max().unwrap_or(0);
-    if config.width > 0 {
-        series.iter_mut().for_each(|s| {
-            if s.len() < len_max {
-                s.extend(vec![f64::NAN].repeat(len_max - s.len()))
-            }
-            *s = interpolate(s, config.width);
-        });
-        len_max = config.width as usize;
+fn legend_marker(config: &Config, series: usize) -> String {
+    if config.area != AreaMode::Lines {
+        area::shade(series).to_string()
+    } else if config.series_styles.is_empty() {
+        "■".to_string()
+    } else {
+        series_glyphs(config, series).horizontal.to_string()
     }
+}
 
-    let mut min = f64::MAX;
-    let mut max = f64::MIN;
+/// Whether series `i` is drawn against the secondary axis, which takes some series on each axis.
+fn on_secondary_axis(config: &Config, series: &[Vec<f64>], i: usize) -> bool {
+    config.secondary_axis.contains(&i)
+        && (0..series.len()).any(|j| !config.secondary_axis.contains(&j))
+}
 
-    (min, max) = series.iter().map(|s| min_max(s)).fold(
-        (min, max),
-        |(current_min, current_max), (next_min, next_max)| {
-            (
-                f64::min(next_min, current_min),
-                f64::max(next_max, current_max),
-            )
-        },
-    );
+/// Series drawn against the secondary axis, none when it is not drawn for lack of values.
+fn secondary_series(config: &Config, series: &[Vec<f64>]) -> Vec<usize> {
+    let secondary: Vec<usize> = (0..series.len())
+        .filter(|&i| on_secondary_axis(config, series, i))
+        .collect();
+    if secondary
+        .iter()
+        .all(|&i| series[i].iter().all(|v| v.is_nan()))
+    {
+        Vec::new()
+    } else {
+        secondary
+    }
+}
 
-    let interval = (max - min).abs();
-    if config.height == 0 {
-        if interval == 0f64 {
-            config.height = 3;
-        } else if interval <= 1f64 {
-            config.height =
-                (interval * f64::from(10i32.pow((-interval.log10()).ceil() as u32))) as u32;
-        } else {
-            config.height = interval as u32;
-        }
+/// Legend of series `i`, naming its axis when the `right` series are drawn on a secondary one.
+fn legend_text(config: &Config, i: usize, right: &[usize]) -> String {
+    let text = &config.series_legends[i];
+    if right.is_empty() {
+        text.clone()
+    } else if right.contains(&i) {
+        format!("{} (right)", text)
+    } else {
+        format!("{} (left)", text)
     }
+}
 
-    if config.offset == 0 {
-        config.offset = 3;
+fn add_legends<M>(
+    canvas: &mut Canvas,
+    config: &Config,
+    len_max: usize,
+    left_pad: usize,
+    right: &[usize],
+    marker: M,
+) where
+    M: Fn(usize) -> String,
+{
+    let right_pad = 3;
+    let legends_text_len = config
+        .series_legends
+        .iter()
+        .enumerate()
+        .map(|(i, _)| legend_text(config, i, right).chars().count() + 2) // 2 for the box and space
+        .sum::<usize>()
+        + right_pad * (config.series_legends.len() - 1);
+
+    canvas.push_row();
+    let row = canvas.push_row();
+    let mut col = left_pad + len_max.saturating_sub(legends_text_len) / 2;
+    for i in 0..config.series_legends.len() {
+        let text = legend_text(config, i, right);
+        canvas.put_str(row, col, &marker(i), Style::Series(i));
+        canvas.put_str(row, col + 1, &format!(" {}", text), Style::Default);
+        col += text.chars().count() + 2 + right_pad;
     }
+}
 
-    let ratio = if interval != 0f64 {
-        f64::from(config.height) / interval
-    } else {
-        1f64
-    };
+/// Draws legends starting at column `col`, one per row for [`LegendPosition::Right`] or on the
+/// row each series ends at for [`LegendPosition::Inline`].
+fn add_side_legends<M>(
+    canvas: &mut Canvas,
+    config: &Config,
+    ends: &[Option<usize>],
+    col: usize,
+    right: &[usize],
+    marker: M,
+) where
+    M: Fn(usize) -> String,
+{
+    let mut next_free: Vec<usize> = Vec::new();
+    for i in 0..config.series_legends.len() {
+        let text = legend_text(config, i, right);
+        let row = match config.legend_position {
+            LegendPosition::Inline => match ends.get(i).copied().flatten() {
+                Some(row) => row,
+                None => continue,
+            },
+            _ => i,
+        };
+        if next_free.len() <= row {
+            next_free.resize(row + 1, col);
+        }
+        let start = next_free[row];
+        canvas.put_str(row, start, &marker(i), Style::Series(i));
+        canvas.put_str(row, start + 1, &format!(" {}", text), Style::Default);
+        next_free[row] = start + text.chars().count() + 4;
+    }
+}
 
-    let min2 = (min * ratio).round();
-    let max2 = (max * ratio).round();
+fn check_input(series: &[Vec<f64>], config: &Config) -> Result<(), PlotError> {
+    if config.width == 1 {
+        return Err(PlotError::InvalidWidth(config.width));
+    }
+    if let Some(index) = series.iter().position(|s| s.is_empty()) {
+        return Err(PlotError::EmptySeries { index });
+    }
+    Ok(())
+}
 
-    let int_min2 = min2 as i32;
-    let int_max2 = max2 as i32;
+/// Turns infinite samples into gaps like missing ones, they have no place on the Y axis.
+fn drop_infinite(series: &mut [Vec<f64>]) {
+    for value in series.iter_mut().flatten() {
+        if value.is_infinite() {
+            *value = f64::NAN;
+        }
+    }
+}
 
-    let rows = f64::from(int_max2 - int_min2).abs() as i32;
-    let width = len_max as u32 + config.offset;
+/// Formats the Y-axis labels from top to bottom, returning them with the widest label width.
+fn y_labels(layout: &Layout, config: &Config) -> (Vec<String>, usize) {
+    let (min, max) = (layout.min, layout.max);
+    let (int_min2, int_max2) = (layout.int_min2, layout.int_max2);
+    let rows = int_max2 - int_min2;
+    let interval = (max - min).abs();
+    let magnitudes: Vec<f64> = (int_min2..=int_max2)
+        .map(|y| {
+            if rows > 0 {
+                max - f64::from(y - int_min2) * interval / f64::from(rows)
+            } else {
+                f64::from(y)
+            }
+        })
+        .collect();
+
+    let scale = layout.scale;
+    let (labels, bounds) = match scale_labels(layout, config) {
+        Some(labels) => (labels, Vec::new()),
+        None => {
+            let format_value = value_formatter(config, scale.inverse(min), scale.inverse(max));
+            let labels = magnitudes
+                .iter()
+                .map(|&magnitude| format_value(scale.inverse(magnitude)))
+                .collect();
+            let bounds = vec![
+                format_value(scale.inverse(min)),
+                format_value(scale.inverse(max)),
+            ];
+            (labels, bounds)
+        }
+    };
+    let max_label_width = labels
+        .iter()
+        .chain(bounds.iter())
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or(0);
+    (labels, max_label_width)
+}
 
-    let mut plot: Vec<Vec<ColoredString>> = Vec::new();
+/// Labels the rows closest to the powers of a logarithmic scale's base, leaving the others blank.
+///
+/// Gives `None` for linear scales and ranges holding fewer than two powers.
+fn scale_labels(layout: &Layout, config: &Config) -> Option<Vec<String>> {
+    let (scale, min, max) = (layout.scale, layout.min, layout.max);
+    let powers: Vec<f64> = match scale {
+        Scale::Linear => return None,
+        Scale::Log10 => (min.ceil() as i32..=max.floor() as i32)
+            .map(|k| 10f64.powi(k))
+            .collect(),
+        Scale::Log2 => (min.ceil() as i32..=max.floor() as i32)
+            .map(|k| 2f64.powi(k))
+            .collect(),
+        Scale::SymLog => {
+            let decades = scale.inverse(f64::max(min.abs(), max.abs())).log10() as i32;
+            let positive = (0..=decades).map(|k| 10f64.powi(k));
+            let mut powers: Vec<f64> = positive.clone().map(|p| -p).rev().collect();
+            powers.push(0f64);
+            powers.extend(positive);
+            powers.retain(|&p| (min..=max).contains(&scale.forward(p)));
+            powers
+        }
+    };
+    if powers.len() < 2 {
+        return None;
+    }
 
-    for _i in 0..=rows {
-        let mut line = Vec::<ColoredString>::new();
-        for _j in 0..width {
-            line.push(" ".to_string().into());
+    let format_value = |value: f64| match (&config.value_formatter, config.precision) {
+        (Some(formatter), _) => formatter(value),
+        (None, Some(precision)) => format!("{:.*}", precision, value),
+        (None, None) => format!("{}", value),
+    };
+    let rows = layout.rows as usize;
+    let mut labels = vec![String::new(); rows + 1];
+    for power in powers {
+        let row = ((max - scale.forward(power)) / (max - min) * rows as f64).round() as usize;
+        if labels[row].is_empty() {
+            labels[row] = format_value(power);
         }
-        plot.push(line);
     }
+    Some(labels)
+}
+
+/// Formats values the way Y-axis labels of a `min..=max` range are.
+fn value_formatter(config: &Config, min: f64, max: f64) -> impl Fn(f64) -> String + '_ {
+    let precision = config
+        .precision
+        .unwrap_or_else(|| label_precision(min, max));
+    move |value| match &config.value_formatter {
+        Some(formatter) => formatter(value),
+        None => format!("{:.*}", precision, value),
+    }
+}
 
+fn label_precision(min: f64, max: f64) -> usize {
     let mut precision = 2;
     let log_maximum = if min == 0f64 && max == 0f64 {
         -1f64
@@ -420,146 +1309,170 @@ pub fn plot_many_colored(mut series: Vec<Vec<f64>>, mut config: Config) -> Color
     } else if log_maximum > 2f64 {
         precision = 0;
     }
+    precision as usize
+}
 
-    let max_number_label_length = format!("{:.*}", precision as usize, max).len();
-    let min_number_label_length = format!("{:.*}", precision as usize, min).len();
-
-    let max_label_width = usize::max(max_number_label_length, min_number_label_length);
-
-    for y in int_min2..=int_max2 {
-        let magnitude = if rows > 0 {
-            max - f64::from(y - int_min2) * interval / f64::from(rows)
+/// Draws the X axis and its tick labels below `canvas` for `columns` plot columns drawn from
+/// `samples` samples, with the Y axis at `left_pad - 1`.
+fn draw_x_axis(
+    canvas: &mut Canvas,
+    columns: usize,
+    samples: usize,
+    left_pad: usize,
+    config: &Config,
+) {
+    let scale = if columns > 1 {
+        samples.saturating_sub(1) as f64 / (columns - 1) as f64
+    } else {
+        1f64
+    };
+    draw_x_ticks(canvas, columns, left_pad, config, |column| {
+        let position = column as f64 * scale;
+        if let Some(formatter) = &config.x_label_formatter {
+            formatter(position)
+        } else if !config.x_labels.is_empty() {
+            config
+                .x_labels
+                .get(position.round() as usize)
+                .cloned()
+                .unwrap_or_default()
+        } else if (position - position.round()).abs() < 1e-9 {
+            format!("{}", position.round())
         } else {
-            f64::from(y)
-        };
-        let label = format!(
-            "{number:LW$.PREC$}",
-            LW = max_label_width + 1,
-            PREC = precision as usize,
-            number = magnitude
-        );
-        let w = (y - int_min2) as usize;
-        let h = f64::max(f64::from(config.offset) - label.len() as f64, 0f64) as usize;
-        plot[w][h] = label.color(config.axis_color);
-        plot[w][(config.offset - 1) as usize] = "┤".to_string().color(config.axis_color);
-    }
-
-    for (i, series_inner) in series.iter().enumerate() {
-        let mut y0;
-        let mut y1;
-        if !series_inner[0].is_nan() {
-            y0 = ((series_inner[0] * ratio).round() - min2) as i32;
-            plot[(rows - y0) as usize][(config.offset - 1) as usize] =
-                "┼".to_string().color(config.axis_color);
+            format!("{:.1}", position)
         }
+    });
+}
 
-        for x in 0..series_inner.len() - 1 {
-            if series_inner[x].is_nan() && series_inner[x + 1].is_nan() {
-                continue;
-            }
-            if series_inner[x + 1].is_nan() && !series_inner[x].is_nan() {
-                y0 = ((series_inner[x] * ratio).round() - f64::from(int_min2)) as i32;
-                plot[(rows - y0) as usize][(x as u32 + config.offset) as usize] =
-                    "─".to_string().color(config.series_colors[i]);
-                continue;
-            }
-            if series_inner[x].is_nan() && !series_inner[x + 1].is_nan() {
-                y1 = ((series_inner[x + 1] * ratio).round() - f64::from(int_min2)) as i32;
-                plot[(rows - y1) as usize][(x as u32 + config.offset) as usize] =
-                    "─".to_string().color(config.series_colors[i]);
-                continue;
-            }
-            y0 = ((series_inner[x] * ratio).round() - f64::from(int_min2)) as i32;
-            y1 = ((series_inner[x + 1] * ratio).round() - f64::from(int_min2)) as i32;
-
-            if y0 == y1 {
-                plot[(rows - y0) as usize][(x as u32 + config.offset) as usize] =
-                    "─".to_string().color(config.series_colors[i]);
-            } else {
-                if y0 > y1 {
-                    plot[(rows - y1) as usize][(x as u32 + config.offset) as usize] =
-                        "╰".to_string().color(config.series_colors[i]);
-                    plot[(rows - y0) as usize][(x as u32 + config.offset) as usize] =
-                        "╮".to_string().color(config.series_colors[i]);
-                } else {
-                    plot[(rows - y1) as usize][(x as u32 + config.offset) as usize] =
-                        "╭".to_string().color(config.series_colors[i]);
-                    plot[(rows - y0) as usize][(x as u32 + config.offset) as usize] =
-                        "╯".to_string().color(config.series_colors[i]);
-                }
-
-                let start = f64::min(f64::from(y0), f64::from(y1)) as i32 + 1;
-                let end = f64::max(f64::from(y0), f64::from(y1)) as i32;
-
-                for y in start..end {
-                    plot[(rows - y) as usize][(x as u32 + config.offset) as usize] =
-                        "│".to_string().color(config.series_colors[i]);
-                }
-            }
+/// Appends an X axis over `columns` with a tick every `x_tick_spacing` columns, labeled by `label`.
+fn draw_x_ticks<L>(canvas: &mut Canvas, columns: usize, left_pad: usize, config: &Config, label: L)
+where
+    L: Fn(usize) -> String,
+{
+    let spacing = usize::max(config.x_tick_spacing as usize, 1);
+    let axis_row = canvas.push_row();
+    let label_row = canvas.push_row();
+    canvas.put_str(axis_row, left_pad - 1, "└", Style::Axis);
+    let mut free = 0;
+    for column in 0..columns {
+        if column % spacing != 0 {
+            canvas.put_str(axis_row, left_pad + column, "─", Style::Axis);
+            continue;
         }
+        canvas.put_str(axis_row, left_pad + column, "┬", Style::Axis);
+
+        let label = label(column);
+        let label_len = label.chars().count();
+        let start = (left_pad + column).saturating_sub(label_len / 2);
+        // keep a blank between neighbours, dropping labels that would overlap
+        if label_len == 0 || start < free {
+            continue;
+        }
+        canvas.put_str(label_row, start, &label, Style::Label);
+        free = start + label_len + 1;
     }
+}
 
-    let mut res: String = plot
-        .into_iter()
-        .map(|line| line.into_iter().map(|s| s.to_string()).collect::<String>())
-        .collect::<Vec<String>>()
-        .join("\n");
-
-    //res.pop();
+/// Extends or clips `min..=max` to the configured bounds mapped onto `scale`.
+fn bounded_range(
+    min: f64,
+    max: f64,
+    scale: Scale,
+    config: &Config,
+) -> Result<(f64, f64), PlotError> {
+    let (lower, upper) = (config.lower_bound, config.upper_bound);
+    if lower.is_some_and(|b| !scale.forward(b).is_finite())
+        || upper.is_some_and(|b| !scale.forward(b).is_finite())
+        || matches!((lower, upper), (Some(l), Some(u)) if l > u)
+    {
+        return Err(PlotError::InvalidBounds { lower, upper });
+    }
+    let (lower, upper) = (
+        lower.map(|b| scale.forward(b)),
+        upper.map(|b| scale.forward(b)),
+    );
 
-    let mut caption = String::new();
+    let (min, max) = if min > max {
+        // no finite samples, only a fully bounded range can be drawn
+        match (lower, upper) {
+            (Some(l), Some(u)) => (l, u),
+            _ => return Err(PlotError::NoFiniteValues),
+        }
+    } else if config.strict_bounds {
+        let l = lower.unwrap_or(min);
+        let u = upper.unwrap_or(max);
+        (f64::min(l, u), f64::max(l, u))
+    } else {
+        (
+            lower.map_or(min, |b| f64::min(min, b)),
+            upper.map_or(max, |b| f64::max(max, b)),
+        )
+    };
+    if !min.is_finite() || !max.is_finite() {
+        return Err(PlotError::NoFiniteValues);
+    }
+    Ok((min, max))
+}
 
-    if !config.caption.is_empty() {
-        caption.push('\n');
-        caption.push_str(
-            std::iter::repeat(" ")
-                .take(config.offset as usize + max_label_width as usize)
-                .collect::<String>()
-                .as_ref(),
-        );
-        if config.caption.len() < len_max {
-            caption.push_str(
-                std::iter::repeat(" ")
-                    .take((len_max - config.caption.len()) / 2)
-                    .collect::<String>()
-                    .as_ref(),
-            );
+/// Clamps samples into `min..=max`, returning `(series, index, above)` for each clamped one.
+fn clip_series(series: &mut [Vec<f64>], min: f64, max: f64) -> Vec<(usize, usize, bool)> {
+    let mut clipped = Vec::new();
+    for (i, s) in series.iter_mut().enumerate() {
+        for (x, v) in s.iter_mut().enumerate() {
+            if *v > max {
+                *v = max;
+                clipped.push((i, x, true));
+            } else if *v < min {
+                *v = min;
+                clipped.push((i, x, false));
+            }
         }
-        caption.push_str(
-            config
-                .caption
-                .color(config.caption_color)
-                .to_string()
-                .as_ref(),
-        );
     }
-    res.push_str(caption.as_ref());
+    clipped
+}
 
-    if config.series_legends.len() > 0 {
-        add_legends(
-            &mut res,
-            &config,
-            len_max,
-            config.offset as usize + max_label_width,
-        )
+fn clip_marker(above: bool) -> char {
+    if above {
+        '▲'
+    } else {
+        '▼'
     }
-    res.into()
 }
 
-fn interpolate(series: &[f64], count: u32) -> Vec<f64> {
-    let mut result = Vec::new();
+fn interpolate(series: &[f64], count: u32, mode: Interpolation) -> Vec<f64> {
     let spring_factor = (series.len() - 1) as f64 / f64::from(count - 1);
+    if mode == Interpolation::None {
+        let mut result = vec![f64::NAN; count as usize];
+        for (i, &value) in series.iter().enumerate() {
+            let column = if spring_factor > 0.0 {
+                (i as f64 / spring_factor).round() as usize
+            } else {
+                0
+            };
+            if !value.is_nan() {
+                result[column] = value;
+            }
+        }
+        return result;
+    }
+    let mut result = Vec::new();
     result.push(series[0]);
     for i in 1..count - 1 {
         let spring = f64::from(i) * spring_factor;
-        let before = spring.floor();
-        let after = spring.ceil();
-        let at_point = spring - before;
-        result.push(linear_interpolate(
-            series[before as usize],
-            series[after as usize],
-            at_point,
-        ))
+        result.push(match mode {
+            // tolerance keeps springs landing on a sample from falling just short of it
+            Interpolation::Step => series[(spring + 1e-9).floor() as usize],
+            Interpolation::Nearest => series[spring.round() as usize],
+            _ => {
+                let before = spring.floor();
+                let after = spring.ceil();
+                linear_interpolate(
+                    series[before as usize],
+                    series[after as usize],
+                    spring - before,
+                )
+            }
+        })
     }
     result.push(series[series.len() - 1]);
     result
@@ -572,10 +1485,10 @@ fn linear_interpolate(before: f64, after: f64, at_point: f64) -> f64 {
 fn min_max(series: &[f64]) -> (f64, f64) {
     let min = series
         .iter()
-        .fold(std::f64::MAX, |accu, &x| if x < accu { x } else { accu });
+        .fold(f64::MAX, |accu, &x| if x < accu { x } else { accu });
     let max = series
         .iter()
-        .fold(std::f64::MIN, |accu, &x| if x > accu { x } else { accu });
+        .fold(f64::MIN, |accu, &x| if x > accu { x } else { accu });
     (min, max)
 }
 
@@ -848,4 +1761,610 @@ mod tests {
         assert_eq!(res, exp);
     }
 
+    #[test]
+    fn test_try_plot_errors(){
+        use crate::{try_plot, try_plot_many, Config, PlotError};
+        assert_eq!(try_plot(vec![], Config::default()), Err(PlotError::EmptySeries { index: 0 }));
+        assert_eq!(try_plot_many(vec![vec![1.0], vec![]], Config::default()), Err(PlotError::EmptySeries { index: 1 }));
+        assert_eq!(try_plot(vec![1.0, 2.0], Config::default().with_width(1)), Err(PlotError::InvalidWidth(1)));
+        assert_eq!(try_plot(vec![f64::NAN, f64::NAN], Config::default()), Err(PlotError::NoFiniteValues));
+        assert_eq!(try_plot_many(vec![], Config::default()), Err(PlotError::NoFiniteValues));
+        assert_eq!(try_plot(vec![1.0, 1.0], Config::default()), Ok(" 1.00 ┼─".to_string()));
+        // tiny ranges get a height without overflowing
+        assert!(try_plot(vec![0.0, 1e-12], Config::default()).is_ok());
+        assert!(try_plot(vec![0.0, 1e-300], Config::default()).is_ok());
+        assert_eq!(try_plot(vec![0.0, 5e-324], Config::default()).unwrap().lines().count(), 1);
+        // infinite samples are gaps like missing ones
+        assert_eq!(try_plot(vec![1.0, f64::INFINITY, 1.0], Config::default()), try_plot(vec![1.0, f64::NAN, 1.0], Config::default()));
+        // the infallible API plots nothing rather than panicking
+        assert_eq!(crate::plot(vec![f64::NAN, f64::NEG_INFINITY], Config::default()), "");
+        assert_eq!(crate::plot_many(vec![], Config::default()), "");
+        assert_eq!(crate::plot(vec![], Config::default()), "");
+        assert_eq!(crate::plot_scatter(&[(f64::NAN, 1.0)], Config::default()), "");
+        assert_eq!(crate::plot_bars(vec![], Config::default()), "");
+        assert_eq!(crate::histogram(&[], Config::default()), "");
+        assert_eq!(crate::plot_candles(&[], Config::default()), "");
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn test_try_plot_colored_missing_colors(){
+        use crate::{try_plot_many_colored, Config, PlotError};
+        let config = Config::default().with_series_colors(vec![colored::Color::Red]);
+        assert_eq!(
+            try_plot_many_colored(vec![vec![1.0, 2.0], vec![2.0, 1.0]], config).map(|s| s.to_string()),
+            Err(PlotError::MissingSeriesColors { required: 2, provided: 1 })
+        );
+    }
+
+    #[test]
+    fn test_bounds(){
+        let res = crate::plot(vec![3.0, 5.0, 4.0], crate::Config::default().with_lower_bound(0.0).with_upper_bound(6.0));
+        let exp = " 6.00 ┤   
+ 5.00 ┤╭╮ 
+ 4.00 ┤│╰ 
+ 3.00 ┼╯  
+ 2.00 ┤   
+ 1.00 ┤   
+ 0.00 ┤  ";
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_strict_bounds(){
+        let res = crate::plot(vec![0.0, 5.0, 10.0, 4.0, 3.0], crate::Config::default().with_height(6)
+            .with_lower_bound(2.0).with_upper_bound(8.0).with_strict_bounds(true));
+        let exp = " 8.00 ┤ ╭▲  
+ 7.00 ┤ ││  
+ 6.00 ┤ ││  
+ 5.00 ┤╭╯│  
+ 4.00 ┤│ ╰╮ 
+ 3.00 ┤│  ╰ 
+ 2.00 ┼▼   ";
+        assert_eq!(res, exp);
+        assert_eq!(
+            crate::try_plot(vec![1.0], crate::Config::default().with_lower_bound(2.0).with_upper_bound(1.0)),
+            Err(crate::PlotError::InvalidBounds { lower: Some(2.0), upper: Some(1.0) })
+        );
+    }
+
+    #[test]
+    fn test_value_formatter(){
+        let res = crate::plot(vec![0.0, 5e6, 1e7, 4e6], crate::Config::default().with_height(4).with_value_formatter(crate::formatters::si(1)));
+        let exp = " 10.0M ┤ ╭╮ 
+  7.5M ┤ ││ 
+  5.0M ┤╭╯╰ 
+  2.5M ┤│   
+   0.0 ┼╯  ";
+        assert_eq!(res, exp);
+
+        let res = crate::plot(vec![3.0, 5.0, 4.0], crate::Config::default().with_offset(4).with_precision(0));
+        let exp = "   5┤╭╮ 
+   4┤│╰ 
+   3┼╯ ";
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_formatters(){
+        use crate::formatters::{bytes, duration, percent, si};
+        assert_eq!(si(1)(1234567.0), "1.2M");
+        assert_eq!(si(0)(0.00000012), "120n");
+        assert_eq!(si(2)(-42.0), "-42.00");
+        assert_eq!(bytes(1)(1536.0), "1.5KiB");
+        assert_eq!(bytes(0)(3.0 * 1024.0 * 1024.0 * 1024.0), "3GiB");
+        assert_eq!(duration(0)(0.25), "250ms");
+        assert_eq!(duration(1)(90.0), "1.5min");
+        assert_eq!(percent(1)(0.256), "25.6%");
+    }
+
+    #[test]
+    fn test_x_axis(){
+        let res = crate::plot(vec![3.0, 5.0, 4.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 7.0, 6.0],
+            crate::Config::default().with_x_tick_spacing(4).with_caption("cap".to_string()));
+        let exp = " 8.00 ┤         ╭╮  
+ 7.00 ┤        ╭╯╰╮ 
+ 6.00 ┤       ╭╯  ╰ 
+ 5.00 ┤╭╮    ╭╯     
+ 4.00 ┤│╰╮  ╭╯      
+ 3.00 ┼╯ │ ╭╯       
+ 2.00 ┤  │╭╯        
+ 1.00 ┤  ╰╯        
+      └┬───┬───┬───┬
+       0   4   8  12
+            cap";
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_x_axis_labels_with_width(){
+        let labels = vec!["mon".to_string(), "tue".to_string(), "wed".to_string(), "thu".to_string()];
+        let res = crate::plot(vec![3.0, 5.0, 4.0, 1.0],
+            crate::Config::default().with_width(16).with_x_labels(labels).with_x_tick_spacing(5));
+        let exp = " 5.00 ┤   ╭───╮        
+ 4.00 ┤ ╭─╯   ╰──╮     
+ 3.00 ┼─╯        ╰─╮   
+ 2.00 ┤            ╰─╮ 
+ 1.00 ┤              ╰
+      └┬────┬────┬────┬
+      mon  tue  wed  thu";
+        assert_eq!(res, exp);
+
+        let res = crate::plot(vec![3.0, 5.0, 4.0, 1.0],
+            crate::Config::default().with_width(7).with_x_label_formatter(|x| format!("t{}", x)).with_x_tick_spacing(3));
+        // "t1.5" would touch "t0" and is dropped
+        assert!(res.ends_with("      └┬──┬──┬\n      t0    t3"));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_plot_time_series(){
+        use chrono::{Duration, TimeZone, Utc};
+        let start = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let points: Vec<_> = [(0, 1.0), (2, 3.0), (3, 2.0), (5, 4.0), (6, 5.0), (20, 2.0), (22, 3.0), (24, 1.0), (25, 2.0), (30, 3.0)]
+            .iter()
+            .map(|&(minutes, value)| (start + Duration::minutes(minutes), value))
+            .collect();
+        let res = crate::plot_time_series(&points, crate::Config::default().with_width(40).with_height(5));
+        let exp = " 5.00 ┤       ╭─                               
+ 4.20 ┤     ╭─╯                                
+ 3.40 ┤  ╭╮╭╯                      ╭╮        ─ 
+ 2.60 ┤ ╭╯╰╯                    ───╯│  ╭─      
+ 1.80 ┤╭╯                           ╰╮╭╯       
+ 1.00 ┼╯                             ╰╯       
+      └┬─────────┬─────────┬─────────┬─────────
+     12:00     12:07     12:15     12:23";
+        assert_eq!(res, exp);
+
+        // repeated timestamps do not turn every gap into missing data
+        let once: Vec<_> = [(0, 1.0), (1, 3.0), (2, 2.0), (3, 4.0), (4, 1.0)]
+            .iter()
+            .map(|&(minutes, value)| (start + Duration::minutes(minutes), value))
+            .collect();
+        let twice: Vec<_> = once.iter().flat_map(|&point| [point, point]).collect();
+        let config = || crate::Config::default().with_width(9).with_height(3);
+        assert_eq!(crate::plot_time_series(&twice, config()), crate::plot_time_series(&once, config()));
+
+        // the fall-back hour repeats local times, points keep their UTC order
+        use chrono::FixedOffset;
+        let (edt, est) = (FixedOffset::west_opt(4 * 3600).unwrap(), FixedOffset::west_opt(5 * 3600).unwrap());
+        let points = [
+            (edt.with_ymd_and_hms(2024, 11, 3, 0, 30, 0).unwrap(), 1.0),
+            (edt.with_ymd_and_hms(2024, 11, 3, 1, 30, 0).unwrap(), 2.0),
+            (est.with_ymd_and_hms(2024, 11, 3, 1, 10, 0).unwrap(), 3.0),
+        ];
+        let res = crate::plot_time_series(&points, crate::Config::default().with_width(14).with_height(2));
+        let exp = " 3.00 ┤          ╭── 
+ 2.00 ┤   ╭──────╯   
+ 1.00 ┼───╯         
+      └┬─────────┬───
+     00:30     01:46";
+        assert_eq!(res, exp);
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn test_colored_matches_plain(){
+        use colored::Color;
+        let strip = |s: &str| {
+            let mut out = String::new();
+            let mut chars = s.chars();
+            while let Some(c) = chars.next() {
+                if c == '\x1b' {
+                    chars.by_ref().find(|&c| c == 'm');
+                } else {
+                    out.push(c);
+                }
+            }
+            out
+        };
+        let config = || crate::Config::default()
+            .with_caption("caption".to_string())
+            .with_x_axis(true)
+            .with_series_colors(vec![Color::Red, Color::Blue])
+            .with_series_legends(vec!["a".to_string(), "b".to_string()]);
+        let series = || vec![vec![1.0, 3.0, 2.0, 5.0], vec![2.0, 2.0, 4.0, 1.0]];
+        let colored = crate::plot_many_colored(series(), config()).to_string();
+        let plain = crate::plot_many(series(), config());
+        assert_eq!(strip(&colored), plain);
+        assert_eq!(plain, " 5.00 ┤  ╭ 
+ 4.00 ┤ ╭╮ 
+ 3.00 ┤╭││ 
+ 2.00 ┼─╯│ 
+ 1.00 ┼╯ ╰
+      └┬───
+       0
+       caption
+
+       ■ a   ■ b");
+    }
+
+    #[test]
+    fn test_series_styles_and_legends(){
+        use crate::{LegendPosition, SeriesStyle};
+        let series = || vec![vec![1.0, 3.0, 2.0, 5.0, 4.0], vec![2.0, 2.0, 4.0, 1.0, 1.0], vec![0.0, 1.0, 1.0, 2.0, 4.0]];
+        let config = || crate::Config::default()
+            .with_series_legends(vec!["cpu".to_string(), "mem".to_string(), "io".to_string()])
+            .with_series_styles(vec![SeriesStyle::Solid, SeriesStyle::Double, SeriesStyle::Dashed]);
+
+        let res = crate::plot_many(series(), config());
+        let exp = " 5.00 ┤  ╭╮ 
+ 4.00 ┤ ╔╗╭ 
+ 3.00 ┤╭║║┆ 
+ 2.00 ┼═╝╭╯ 
+ 1.00 ┼╭┄╯═ 
+ 0.00 ┼╯   
+
+       ─ cpu   ═ mem   ┄ io";
+        assert_eq!(res, exp);
+
+        let res = crate::plot_many(series(), config().with_legend_position(LegendPosition::Right));
+        assert!(res.starts_with(" 5.00 ┤  ╭╮  ─ cpu\n 4.00 ┤ ╔╗╭  ═ mem\n 3.00 ┤╭║║┆  ┄ io\n"));
+
+        let res = crate::plot_many(series(), config().with_legend_position(LegendPosition::Inline));
+        let exp = " 5.00 ┤  ╭╮ 
+ 4.00 ┤ ╔╗╭  ─ cpu  ┄ io
+ 3.00 ┤╭║║┆ 
+ 2.00 ┼═╝╭╯ 
+ 1.00 ┼╭┄╯═  ═ mem
+ 0.00 ┼╯   ";
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_live_plot(){
+        let mut live = crate::LivePlot::with_series(2, 4, crate::Config::default());
+        for i in 0..6 {
+            live.push_many(&[f64::from(i), f64::from(5 - i)]);
+        }
+        let expected = crate::plot_many(vec![vec![2.0, 3.0, 4.0, 5.0], vec![3.0, 2.0, 1.0, 0.0]], crate::Config::default());
+        assert_eq!(live.render().unwrap(), expected);
+        // same range, the cached axis is reused
+        live.push_many(&[2.0, 3.0]);
+        let expected = crate::plot_many(vec![vec![3.0, 4.0, 5.0, 2.0], vec![2.0, 1.0, 0.0, 3.0]], crate::Config::default());
+        assert_eq!(live.render().unwrap(), expected);
+
+        let mut out = Vec::new();
+        live.redraw(&mut out).unwrap();
+        live.redraw(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out, format!("{}\x1b[5A\r\x1b[J{}", expected, expected));
+
+        // shares the line plot pipeline, secondary axis and all
+        let config = || crate::Config::default().with_height(4).with_secondary_axis(vec![1]).with_area(crate::AreaMode::Filled);
+        let mut live = crate::LivePlot::with_series(2, 3, config());
+        for i in 0..3 {
+            live.push_many(&[f64::from(i), f64::from(i * 100)]);
+        }
+        assert_eq!(live.render().unwrap(), crate::plot_many(vec![vec![0.0, 1.0, 2.0], vec![0.0, 100.0, 200.0]], config()));
+
+        // a single value leaves a gap in the other series, keeping them aligned
+        let mut live = crate::LivePlot::with_series(2, 3, crate::Config::default());
+        live.push_many(&[1.0, 4.0]);
+        live.push(2.0);
+        live.push_many(&[3.0, 6.0]);
+        assert_eq!(live.render().unwrap(), crate::plot_many(vec![vec![1.0, 2.0, 3.0], vec![4.0, f64::NAN, 6.0]], crate::Config::default()));
+    }
+
+    #[test]
+    fn test_plot_bars(){
+        let labels = vec!["mon".to_string(), "tue".to_string(), "wed".to_string(), "thu".to_string()];
+        let config = crate::Config::default().with_height(5).with_bar_values(true).with_x_labels(labels).with_caption("visits".to_string());
+        let res = crate::plot_bars(vec![3.0, 5.5, 1.25, 4.0], config);
+        let exp = "            5.50          
+ 5.50 ┤     ████      4.00
+ 4.40 ┤3.00 ████      ▅▅▅▅
+ 3.30 ┤▆▆▆▆ ████ 1.25 ████
+ 2.20 ┤████ ████ ▁▁▁▁ ████
+ 1.10 ┤████ ████ ████ ████
+ 0.00 ┤                   
+       mon  tue  wed  thu
+             visits";
+        assert_eq!(res, exp);
+
+        let res = crate::plot_bars(vec![3.0, -2.5, 1.25, -4.0], crate::Config::default().with_height(6).with_bar_values(true));
+        let exp = "        3.00                   
+  3.00 ┤█████       1.25       
+  1.83 ┤█████       ▄▄▄▄▄      
+  0.67 ┤█████       █████      
+ -0.50 ┤      █████       █████
+ -1.67 ┤      ▀▀▀▀▀       █████
+ -2.83 ┤      -2.50       █████
+ -4.00 ┤                  -4.00";
+        assert_eq!(res, exp);
+
+        assert_eq!(crate::try_plot_bars(vec![], crate::Config::default()), Err(crate::PlotError::EmptySeries { index: 0 }));
+        assert_eq!(crate::try_plot_bars(vec![1.0, 2.0, 3.0], crate::Config::default().with_width(2)), Err(crate::PlotError::InvalidWidth(2)));
+        assert_eq!(crate::try_plot_bars(vec![1.0, 2.0, 3.0], crate::Config::default().with_width(5).with_height(2)).unwrap(), " 3.00 ┤  ▃ █\n 1.50 ┤▅ █ █\n 0.00 ┤     ");
+    }
+
+    #[test]
+    fn test_plot_hbar(){
+        let items = [("/api/users", 120.5), ("/api/orders", 340.0), ("/health", 3.2), ("/api/search", 980.25)];
+        let config = crate::Config::default().with_width(30).with_bar_order(crate::BarOrder::Descending).with_caption("p99 latency (ms)".to_string());
+        let res = crate::plot_hbar(&items, config);
+        let exp = "/api/search ┤██████████████████████████████ 980
+/api/orders ┤██████████▍ 340
+/api/users  ┤███▊ 120
+/health     ┤▏ 3
+                   p99 latency (ms)";
+        assert_eq!(res, exp);
+
+        let items = [("a", 3.0), ("bb", -2.5), ("ccc", 1.3), ("d", -4.0), ("e", f64::NAN)];
+        let res = crate::plot_hbar(&items, crate::Config::default().with_width(20));
+        let exp = "a                    ┤████████▋ 3.00
+bb      -2.50 ███████┤
+ccc                  ┤███▊ 1.30
+d   -4.00 ███████████┤
+e                    ┤";
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_sparkline(){
+        let series = [1.0, 2.0, 5.0, 7.0, 3.0, 1.0];
+        assert_eq!(crate::sparkline(&series, 0), "▁▂▆█▃▁");
+        assert_eq!(crate::sparkline(&series, 11), "▁▂▂▄▆▇█▆▃▂▁");
+        assert_eq!(crate::sparkline(&[1.0, f64::NAN, 3.0], 0), "▁ █");
+        assert_eq!(crate::sparkline(&[2.0, 2.0], 0), "▁▁");
+        assert_eq!(crate::sparkline(&[], 10), "");
+        assert_eq!(crate::sparkline_with_summary(&[4.0, 1.0, 8.0, f64::NAN], 0, 2), "▄▁█  min 1.00 max 8.00 last 8.00");
+    }
+
+    #[test]
+    fn test_braille(){
+        let res = crate::plot(vec![0.0, 1.0, 2.0, 3.0, 4.0, 3.0, 2.0, 1.0], crate::Config::default().with_height(2).with_braille(true));
+        let exp = " 4.00 ┤ ⢀⢦ 
+ 2.00 ┤⢀⠎ ⢣
+ 0.00 ┼⠊  ";
+        assert_eq!(res, exp);
+
+        // the width counts cells, each holding two samples
+        let res = crate::plot(vec![0.0, 8.0, 0.0, 8.0], crate::Config::default().with_height(2).with_width(4).with_braille(true));
+        assert!(res.lines().all(|line| line.chars().count() <= 11));
+        assert_eq!(res.lines().next(), Some(" 8.00 ┤ ⡀ ⢰"));
+    }
+
+    #[test]
+    fn test_plot_scatter(){
+        let up = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0), (4.0, 4.0)];
+        let down = [(0.0, 4.0), (2.0, 2.0), (4.0, 0.0), (4.0, 0.0)];
+        let config = crate::Config::default().with_height(4).with_width(9).with_x_tick_spacing(4)
+            .with_markers(vec![crate::Marker::Dot, crate::Marker::Cross])
+            .with_series_legends(vec!["up".to_string(), "down".to_string()]);
+        let res = crate::plot_scatter_many(&[&up, &down], config);
+        let exp = " 4.00 ┤×       •
+ 3.00 ┤      •  
+ 2.00 ┤    2    
+ 1.00 ┤  •      
+ 0.00 ┤•       2
+      └┬───┬───┬
+     0.00    4.00
+
+       • up   × down";
+        assert_eq!(res, exp);
+
+        assert_eq!(crate::try_plot_scatter(&[(f64::NAN, 1.0)], crate::Config::default()), Err(crate::PlotError::NoFiniteValues));
+    }
+
+    #[test]
+    fn test_histogram(){
+        let bins = crate::bins(&[1.0, 2.0, 2.5, 4.0, f64::NAN], crate::Binning::Width(2.0)).unwrap();
+        assert_eq!(bins, vec![crate::Bin { start: 0.0, end: 2.0, count: 1 }, crate::Bin { start: 2.0, end: 4.0, count: 3 }]);
+        let counts = |binning| crate::bins(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 100.0], binning).unwrap().iter().map(|b| b.count).collect::<Vec<_>>();
+        assert_eq!(counts(crate::Binning::Count(2)), vec![7, 1]);
+        assert_eq!(counts(crate::Binning::Sturges), vec![7, 0, 0, 1]);
+        assert_eq!(counts(crate::Binning::FreedmanDiaconis).len(), 29);
+        assert_eq!(crate::bins(&[1.0], crate::Binning::Width(0.0)), Err(crate::PlotError::InvalidBinning(crate::Binning::Width(0.0))));
+
+        // a far outlier must not explode the number of bins
+        let mut latencies: Vec<f64> = (0..1000).map(|i| 10.0 + (i % 7) as f64 * 0.01).collect();
+        latencies.push(30000.0);
+        assert_eq!(crate::bins(&latencies, crate::Binning::FreedmanDiaconis).unwrap().len(), 11);
+        assert_eq!(crate::bins(&latencies, crate::Binning::Width(0.001)), Err(crate::PlotError::InvalidBinning(crate::Binning::Width(0.001))));
+        assert_eq!(crate::bins(&latencies, crate::Binning::Count(usize::MAX)), Err(crate::PlotError::InvalidBinning(crate::Binning::Count(usize::MAX))));
+        assert!(crate::histogram(&latencies, crate::Config::default().with_binning(crate::Binning::FreedmanDiaconis)).lines().all(|l| l.chars().count() < 100));
+
+        let res = crate::histogram(&[1.0, 2.0, 2.5, 4.0], crate::Config::default().with_height(3).with_binning(crate::Binning::Width(2.0)).with_bar_values(true));
+        let exp = "          3  
+  3┤     ████
+  2┤ 1   ████
+  1┤████ ████
+  0┤         
+   └────┬────┬
+ 0.00 2.00 4.00";
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_y_scale(){
+        let res = crate::plot(vec![1.0, 4.0, 16.0, 64.0], crate::Config::default().with_height(6).with_y_scale(crate::Scale::Log2));
+        let exp = " 64 ┤  ╭ 
+ 32 ┤  │ 
+ 16 ┤ ╭╯ 
+  8 ┤ │  
+  4 ┤╭╯  
+  2 ┤│   
+  1 ┼╯  ";
+        assert_eq!(res, exp);
+
+        let res = crate::plot(vec![-1000.0, -50.0, 0.0, 3.0, 100.0, 800.0], crate::Config::default().with_height(12).with_y_scale(crate::Scale::SymLog));
+        assert!(res.contains("\n    0 ┤ ╭╯   \n   -1 ┤ │    \n  -10 ┤ │    \n"));
+
+        // too narrow for two decades, every row is labeled
+        let res = crate::plot(vec![20.0, 30.0, 50.0], crate::Config::default().with_height(2).with_y_scale(crate::Scale::Log10));
+        assert_eq!(res, " 50.00 ┤ ╭ \n 31.62 ┤ │ \n 20.00 ┼─╯");
+
+        let err = crate::try_plot(vec![0.0, 1.0], crate::Config::default().with_y_scale(crate::Scale::Log10));
+        assert_eq!(err, Err(crate::PlotError::NonPositiveValue(0.0)));
+    }
+
+    #[test]
+    fn test_hline(){
+        // a far reference line caps the automatic height instead of drawing a huge grid
+        let res = crate::plot(vec![1.0, 2.0], crate::Config::default().with_hline(1e9, "far".to_string(), crate::SeriesStyle::Dashed));
+        assert_eq!(res.lines().count(), 101);
+        let series = vec![120.0, 180.0, 210.0, 160.0, 190.0, 230.0, 170.0];
+        let res = crate::plot(series, crate::Config::default().with_height(6).with_hline(250.0, "p99 = 250ms".to_string(), crate::SeriesStyle::Dashed));
+        let exp = " 250 ┤┄┄┄┄┄┄┄ p99 = 250ms
+ 228 ┤    ╭╮ 
+ 207 ┤ ╭╮ ││ 
+ 185 ┤ ││╭╯│ 
+ 163 ┤╭╯││ ╰ 
+ 142 ┤│ ╰╯   
+ 120 ┼╯     ";
+        assert_eq!(res, exp);
+
+        // series are drawn over the line
+        let res = crate::plot_bars(vec![3.0, 5.0, 1.0], crate::Config::default().with_height(4).with_hline(4.0, "goal".to_string(), crate::SeriesStyle::Dashed));
+        assert!(res.contains("\n 3.75 ┤▃┄█┄┄ goal\n"));
+    }
+
+    #[test]
+    fn test_annotations(){
+        let series = vec![2.0, 3.0, 4.0, 5.0, 4.0, 3.0, 2.0, 3.0];
+        let config = || crate::Config::default().with_height(3)
+            .with_annotation(1, "deploy".to_string())
+            .with_annotation(3, "incident".to_string())
+            .with_annotation(6, "fix".to_string());
+        let res = crate::plot(series.clone(), config());
+        let exp = "             fix
+          incident
+        deploy 
+ 5.00 ┤ ┊╭╮  ┊ 
+ 4.00 ┤ ╭╯╰╮ ┊ 
+ 3.00 ┤╭╯ ┊╰╮╭ 
+ 2.00 ┼╯┊ ┊ ╰╯";
+        assert_eq!(res, exp);
+
+        let res = crate::plot(series, config().with_width(15).with_annotation_position(crate::AnnotationPosition::Footnotes));
+        assert!(res.starts_with("         1   2     3  \n 5.00 ┤  ┊ ╭──╮    ┊  \n"));
+        assert!(res.ends_with("\n       1 deploy\n       2 incident\n       3 fix"));
+    }
+
+    #[test]
+    fn test_secondary_axis(){
+        let rate = vec![1200.0, 1500.0, 1800.0, 1700.0, 2100.0, 2400.0, 2200.0, 2600.0];
+        let errors = vec![0.5, 0.4, 1.2, 2.5, 1.0, 0.6, 0.3, 0.2];
+        let config = crate::Config::default().with_height(6).with_secondary_axis(vec![1])
+            .with_series_legends(vec!["req/s".to_string(), "errors %".to_string()])
+            .with_series_styles(vec![crate::SeriesStyle::Solid, crate::SeriesStyle::Dashed]);
+        let res = crate::plot_many(vec![rate, errors], config);
+        let exp = " 2600 ┤  ╭╮  ╭ ├ 2.50
+ 2367 ┤  ┆┆╭╮│ ├ 2.12
+ 2133 ┤  ┆┆╯╰╯ ├ 1.73
+ 1900 ┤ ╭╯┆    ├ 1.35
+ 1667 ┤ ┆╰╰╮   ├ 0.97
+ 1433 ┤┄╯  ╰╮  ├ 0.58
+ 1200 ┼╯    ╰┄ ├ 0.20
+
+       ─ req/s (left)   ┄ errors % (right)";
+        assert_eq!(res, exp);
+
+        // no right axis is drawn, so legends name none
+        let legend = |secondary| crate::plot(vec![1.0, 2.0, 3.0], crate::Config::default().with_secondary_axis(secondary).with_series_legends(vec!["a".to_string()]));
+        assert!(legend(vec![0]).ends_with("■ a"));
+        assert!(legend(vec![5]).ends_with("■ a"));
+    }
+
+    #[test]
+    fn test_area(){
+        let config = crate::Config::default().with_height(7).with_area(crate::AreaMode::Stacked).with_series_legends(vec!["a".to_string(), "b".to_string()]);
+        let res = crate::plot_many(vec![vec![2.0, 2.0, 3.0, 3.0], vec![3.0, 4.0, 3.0, 4.0]], config);
+        let exp = " 7.00 ┤  ╭ 
+ 6.29 ┤  │ 
+ 5.57 ┤╭─╯ 
+ 4.86 ┼╯▒▒ 
+ 4.14 ┤▒▒▒ 
+ 3.43 ┤▒▒▒ 
+ 2.71 ┤▒╭─ 
+ 2.00 ┼─╯░
+
+       ░ a   ▒ b";
+        assert_eq!(res, exp);
+
+        // negative values are shaded up to zero
+        let res = crate::plot(vec![3.0, -2.0, -4.0, 1.0, 4.0, 2.0], crate::Config::default().with_height(8).with_area(crate::AreaMode::Filled));
+        assert!(res.contains("\n  0.00 ┤│░│░░ \n -1.00 ┤│░│   \n"));
+
+        // Braille cells shade the two samples they hold
+        let mut series = vec![1.0; 10];
+        series.extend(vec![5.0; 10]);
+        let res = crate::plot(series, crate::Config::default().with_height(5).with_braille(true).with_area(crate::AreaMode::Filled).with_lower_bound(0.0));
+        let exp = " 5.00 ┤     ⡖⠒⠒⠒⠒
+ 4.00 ┤     ⡇░░░░
+ 3.00 ┤    ⢠⠃░░░░
+ 2.00 ┤    ⢸░░░░░
+ 1.00 ┼⠒⠒⠒⠒⠚░░░░░
+ 0.00 ┤░░░░░░░░░░";
+        assert_eq!(res, exp);
+    }
+
+
+    #[test]
+    fn test_interpolation(){
+        let config = crate::Config::default().with_height(4).with_width(12).with_interpolation(crate::Interpolation::Step);
+        let res = crate::plot(vec![1.0, 1.0, 3.0, 3.0, 2.0], config);
+        let exp = " 3.00 ┤     ┌────┐ 
+ 2.50 ┤     │    │ 
+ 2.00 ┤     │    └ 
+ 1.50 ┤     │      
+ 1.00 ┼─────┘     ";
+        assert_eq!(res, exp);
+
+        assert_eq!(crate::interpolate(&[1.0, 3.0, 2.0], 5, crate::Interpolation::Nearest), vec![1.0, 3.0, 3.0, 2.0, 2.0]);
+        let res = crate::interpolate(&[1.0, 3.0, 2.0], 5, crate::Interpolation::None);
+        assert!(res[1].is_nan() && res[3].is_nan());
+        assert_eq!((res[0], res[2], res[4]), (1.0, 3.0, 2.0));
+    }
+
+
+    #[test]
+    fn test_aggregation(){
+        let series = vec![1.0, 2.0, 1.0, 1.0, 8.0, 1.0, 2.0, 3.0, 2.0, 4.0, 4.0, 4.0];
+        let config = crate::Config::default().with_height(4).with_width(6).with_aggregation(crate::Aggregation::MinMax);
+        let res = crate::plot(series.clone(), config);
+        let exp = " 8.00 ┤  │   
+ 6.25 ┤  │   
+ 4.50 ┤ ╭╮   
+ 2.75 ┤ ││╭─│
+ 1.00 ┼─╯╰╯│";
+        assert_eq!(res, exp);
+
+        let (max, _) = crate::aggregate(&series, 6, crate::Aggregation::Max);
+        assert_eq!(max, vec![2.0, 1.0, 8.0, 3.0, 4.0, 4.0]);
+        let (lttb, _) = crate::aggregate(&series, 6, crate::Aggregation::Lttb);
+        assert_eq!(lttb, vec![1.0, 1.0, 8.0, 2.0, 4.0, 4.0]);
+        let (lttb, _) = crate::aggregate(&[1.0, 3.0, 2.0, 5.0, 1.0, 2.0], 2, crate::Aggregation::Lttb);
+        assert_eq!(lttb, vec![1.0, 2.0]);
+    }
+
+
+    #[test]
+    fn test_plot_candles(){
+        let candles = [
+            crate::Ohlc::new(10.0, 12.0, 9.0, 11.5),
+            crate::Ohlc::new(11.5, 13.0, 11.0, 12.5),
+            crate::Ohlc::new(12.5, 12.8, 10.0, 10.5),
+            crate::Ohlc::new(10.5, 11.0, 8.0, 9.0),
+            crate::Ohlc::new(9.0, 10.5, 8.5, 10.0),
+        ];
+        let res = crate::plot_candles(&candles, crate::Config::default().with_height(8));
+        let exp = " 13.00 ┤ │   
+ 12.38 ┤ ┃█  
+ 11.75 ┤│┃█  
+ 11.12 ┤┃┃█│ 
+ 10.50 ┤┃ ██│
+  9.88 ┤┃ │█┃
+  9.25 ┤│  █┃
+  8.62 ┤│  █┃
+  8.00 ┤   │ ";
+        assert_eq!(res, exp);
+
+        // narrower than the periods, neighbours merge into one candle
+        let res = crate::plot_candles(&candles, crate::Config::default().with_height(8).with_width(2));
+        assert!(res.starts_with(" 13.00 ┤│ \n 12.38 ┤┃█\n"));
+        assert_eq!(crate::try_plot_candles(&[], crate::Config::default()), Err(crate::PlotError::EmptySeries { index: 0 }));
+    }
+
 }
@@ -0,0 +1,167 @@
+use std::collections::VecDeque;
+use std::io::{self, Write};
+
+#[cfg(feature = "color")]
+use colored::ColoredString;
+
+use crate::canvas::Canvas;
+use crate::{draw_axes, draw_prepared, prepare_lines, Axes, Config, PlotError};
+
+/// Line plot of the last samples of one or more series, for data arriving over time.
+///
+/// Samples are kept in a fixed-size ring buffer per series. Rendering reuses the previous frame's
+/// grid and only formats new Y-axis labels when the plotted range changes.
+///
+/// ```
+/// use rasciigraph::{Config, LivePlot};
+///
+/// let mut live = LivePlot::new(3, Config::default());
+/// for value in [1.0, 2.0, 5.0, 3.0] {
+///     live.push(value);
+/// }
+/// assert_eq!(live.render().unwrap(), rasciigraph::plot(vec![2.0, 5.0, 3.0], Config::default()));
+/// ```
+pub struct LivePlot {
+    config: Config,
+    capacity: usize,
+    series: Vec<VecDeque<f64>>,
+    axis: Option<(Axes, Canvas, usize)>,
+    frame: Canvas,
+    drawn_lines: usize,
+}
+
+impl LivePlot {
+    /// Creates a plot of a single series keeping its last `capacity` samples.
+    pub fn new(capacity: usize, config: Config) -> Self {
+        Self::with_series(1, capacity, config)
+    }
+
+    /// Creates a plot of `series` series keeping the last `capacity` samples of each.
+    pub fn with_series(series: usize, capacity: usize, config: Config) -> Self {
+        let capacity = usize::max(capacity, 1);
+        LivePlot {
+            config,
+            capacity,
+            series: vec![VecDeque::with_capacity(capacity); usize::max(series, 1)],
+            axis: None,
+            frame: Canvas::default(),
+            drawn_lines: 0,
+        }
+    }
+
+    /// Appends a sample to the first series, dropping its oldest one when full. Any other series
+    /// get a NaN gap, see [`push_many`](LivePlot::push_many).
+    pub fn push(&mut self, value: f64) {
+        self.push_many(&[value]);
+    }
+
+    /// Appends one sample to every series, `values[i]` going to series `i`. Series without a
+    /// value get a NaN gap so all series stay aligned, extra values are ignored.
+    pub fn push_many(&mut self, values: &[f64]) {
+        for i in 0..self.series.len() {
+            self.push_to(i, values.get(i).copied().unwrap_or(f64::NAN));
+        }
+    }
+
+    fn push_to(&mut self, series: usize, value: f64) {
+        let buffer = &mut self.series[series];
+        if buffer.len() == self.capacity {
+            buffer.pop_front();
+        }
+        buffer.push_back(value);
+    }
+
+    /// Number of samples buffered in the longest series.
+    pub fn len(&self) -> usize {
+        self.series.iter().map(|s| s.len()).max().unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops all buffered samples.
+    pub fn clear(&mut self) {
+        self.series.iter_mut().for_each(|s| s.clear());
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Renders the buffered samples, see [`try_plot_many`](crate::try_plot_many).
+    pub fn render(&mut self) -> Result<String, PlotError> {
+        Ok(crate::render_plain(self.draw()?))
+    }
+
+    /// Renders the buffered samples using `config` colors, see
+    /// [`try_plot_many_colored`](crate::try_plot_many_colored).
+    #[cfg(feature = "color")]
+    pub fn render_colored(&mut self) -> Result<ColoredString, PlotError> {
+        crate::check_series_colors(self.series.len(), &self.config)?;
+        self.draw()?;
+        Ok(crate::render_colored(&self.frame, &self.config))
+    }
+
+    /// Writes the plot to a terminal, replacing the frame written by the previous call.
+    pub fn redraw<W: Write>(&mut self, out: &mut W) -> io::Result<()> {
+        let text = self.render().map_err(invalid_input)?;
+        self.replace_frame(out, &text)
+    }
+
+    /// Colored version of [`LivePlot::redraw`].
+    #[cfg(feature = "color")]
+    pub fn redraw_colored<W: Write>(&mut self, out: &mut W) -> io::Result<()> {
+        let text = self.render_colored().map_err(invalid_input)?.to_string();
+        self.replace_frame(out, &text)
+    }
+
+    /// Leaves the frame written last on screen, the next redraw writes a new one where the output
+    /// stands then. Returns whether there was a frame, e.g. to end its line before writing other
+    /// text to the terminal.
+    pub fn keep_frame(&mut self) -> bool {
+        std::mem::take(&mut self.drawn_lines) > 0
+    }
+
+    fn replace_frame<W: Write>(&mut self, out: &mut W, text: &str) -> io::Result<()> {
+        if self.drawn_lines > 1 {
+            // back to the first line of the previous frame
+            write!(out, "\x1b[{}A", self.drawn_lines - 1)?;
+        }
+        if self.drawn_lines > 0 {
+            // clear the previous frame from there down
+            write!(out, "\r\x1b[J")?;
+        }
+        out.write_all(text.as_bytes())?;
+        out.flush()?;
+        self.drawn_lines = text.split('\n').count();
+        Ok(())
+    }
+
+    fn draw(&mut self) -> Result<&Canvas, PlotError> {
+        let series: Vec<Vec<f64>> = self
+            .series
+            .iter()
+            .map(|s| s.iter().copied().collect())
+            .collect();
+        let lines = prepare_lines(series, &self.config)?;
+        let (config, cache) = (&self.config, &mut self.axis);
+        draw_prepared(&mut self.frame, lines, config, |frame, axes| {
+            // the axes only change along with the plotted range
+            let (_, canvas, left_pad) = match cache {
+                Some(cached) if cached.0 == *axes => cached,
+                _ => {
+                    let (canvas, left_pad) = draw_axes(axes, config);
+                    cache.insert((*axes, canvas, left_pad))
+                }
+            };
+            frame.copy_from(canvas);
+            *left_pad
+        });
+        Ok(&self.frame)
+    }
+}
+
+fn invalid_input(err: PlotError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, err)
+}
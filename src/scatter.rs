@@ -0,0 +1,223 @@
+#[cfg(feature = "color")]
+use colored::ColoredString;
+
+use crate::canvas::{Canvas, Style};
+use crate::{
+    add_legends, add_side_legends, clip_marker, draw_caption, draw_hline_labels, draw_hlines,
+    draw_x_ticks, draw_y_axis, label_precision, min_max, scale_series, Config, Layout,
+    LegendPosition, PlotError,
+};
+
+/// Columns scatter plots span when [`Config::with_width`] is not set.
+const SCATTER_WIDTH: usize = 60;
+
+/// Plots `(x, y)` points with both axes scaled to the data and labeled.
+///
+/// Points are drawn with the series' [`Config::with_markers`] marker, cells holding several
+/// points show their count instead, `*` for more than 9. The X-axis labels are formatted with
+/// [`Config::with_x_label_formatter`], which receives X values here.
+///
+/// # Panics
+///
+/// Panics on input [`try_plot_scatter`] rejects with a [`PlotError`], except for empty input and
+/// input without finite values, which plot as an empty string.
+pub fn plot_scatter(points: &[(f64, f64)], config: Config) -> String {
+    plot_scatter_many(&[points], config)
+}
+
+/// Plots multiple point sets on the same graph, see [`plot_scatter`].
+///
+/// # Panics
+///
+/// Panics on input [`try_plot_scatter_many`] rejects with a [`PlotError`], except for empty input
+/// and input without finite values, which plot as an empty string.
+pub fn plot_scatter_many(series: &[&[(f64, f64)]], config: Config) -> String {
+    crate::unwrap_plot(try_plot_scatter_many(series, config))
+}
+
+/// Fallible version of [`plot_scatter`].
+pub fn try_plot_scatter(points: &[(f64, f64)], config: Config) -> Result<String, PlotError> {
+    try_plot_scatter_many(&[points], config)
+}
+
+/// Fallible version of [`plot_scatter_many`].
+pub fn try_plot_scatter_many(
+    series: &[&[(f64, f64)]],
+    config: Config,
+) -> Result<String, PlotError> {
+    let canvas = draw_scatter(series, &config)?;
+    Ok(crate::render_plain(&canvas))
+}
+
+/// Plots `(x, y)` points using `config` colors, see [`plot_scatter`].
+///
+/// # Panics
+///
+/// Panics on input [`try_plot_scatter_colored`] rejects with a [`PlotError`], except for empty
+/// input and input without finite values, which plot as an empty string.
+#[cfg(feature = "color")]
+pub fn plot_scatter_colored(points: &[(f64, f64)], config: Config) -> ColoredString {
+    plot_scatter_many_colored(&[points], config)
+}
+
+/// Plots multiple point sets using `config` colors, see [`plot_scatter`].
+///
+/// # Panics
+///
+/// Panics on input [`try_plot_scatter_many_colored`] rejects with a [`PlotError`], except for empty
+/// input and input without finite values, which plot as an empty string.
+#[cfg(feature = "color")]
+pub fn plot_scatter_many_colored(series: &[&[(f64, f64)]], config: Config) -> ColoredString {
+    crate::unwrap_plot(try_plot_scatter_many_colored(series, config))
+}
+
+/// Fallible version of [`plot_scatter_colored`].
+#[cfg(feature = "color")]
+pub fn try_plot_scatter_colored(
+    points: &[(f64, f64)],
+    config: Config,
+) -> Result<ColoredString, PlotError> {
+    try_plot_scatter_many_colored(&[points], config)
+}
+
+/// Fallible version of [`plot_scatter_many_colored`].
+#[cfg(feature = "color")]
+pub fn try_plot_scatter_many_colored(
+    series: &[&[(f64, f64)]],
+    config: Config,
+) -> Result<ColoredString, PlotError> {
+    crate::check_series_colors(series.len(), &config)?;
+    let canvas = draw_scatter(series, &config)?;
+    Ok(crate::render_colored(&canvas, &config))
+}
+
+/// Points landing in one cell.
+#[derive(Clone, Copy, Default)]
+struct Hits {
+    count: usize,
+    series: usize,
+    mixed: bool,
+}
+
+fn draw_scatter(series: &[&[(f64, f64)]], config: &Config) -> Result<Canvas, PlotError> {
+    if config.width == 1 {
+        return Err(PlotError::InvalidWidth(config.width));
+    }
+    if let Some(index) = series.iter().position(|s| s.is_empty()) {
+        return Err(PlotError::EmptySeries { index });
+    }
+    let finite = |v: f64| if v.is_finite() { v } else { f64::NAN };
+    let series: Vec<Vec<(f64, f64)>> = series
+        .iter()
+        .map(|s| s.iter().map(|&(x, y)| (finite(x), finite(y))).collect())
+        .collect();
+
+    let columns = if config.width > 0 {
+        config.width as usize
+    } else {
+        SCATTER_WIDTH
+    };
+    let mut ys: Vec<Vec<f64>> = series
+        .iter()
+        .map(|s| s.iter().map(|p| p.1).collect())
+        .collect();
+    scale_series(&mut ys, config.y_scale)?;
+    let mut layout = Layout::new(&ys, columns, config.y_scale, config)?;
+    layout.len_max = columns;
+    let xs: Vec<f64> = series.iter().flatten().map(|p| p.0).collect();
+    let (x_min, x_max) = min_max(&xs);
+    if x_min > x_max {
+        return Err(PlotError::NoFiniteValues);
+    }
+    let column_of = |x: f64| {
+        if x_max > x_min {
+            ((x - x_min) / (x_max - x_min) * (columns - 1) as f64).round() as usize
+        } else {
+            (columns - 1) / 2
+        }
+    };
+
+    let (mut canvas, left_pad) = draw_y_axis(&layout, config);
+    draw_hlines(&mut canvas, &layout, left_pad, config);
+    let rows = layout.rows as usize + 1;
+    let mut hits = vec![vec![Hits::default(); columns]; rows];
+    let mut clipped = Vec::new();
+    let mut ends = vec![None; series.len()];
+    for (i, points) in series.iter().enumerate() {
+        let points = points.iter().zip(&ys[i]).map(|(p, &y)| (p.0, y));
+        for (x, y) in points.filter(|(x, y)| !x.is_nan() && !y.is_nan()) {
+            let col = column_of(x);
+            // only strict bounds leave points outside of the range
+            if y > layout.max || y < layout.min {
+                clipped.push((i, col, y > layout.max));
+                continue;
+            }
+            let row = layout.at(layout.scaled(y));
+            let cell = &mut hits[row][col];
+            cell.mixed |= cell.count > 0 && cell.series != i;
+            cell.series = i;
+            cell.count += 1;
+            ends[i] = Some(row);
+        }
+    }
+
+    for (row, cells) in hits.iter().enumerate() {
+        for (col, cell) in cells.iter().enumerate() {
+            let ch = match cell.count {
+                0 => continue,
+                1 => marker(config, cell.series),
+                n => std::char::from_digit(n as u32, 10).unwrap_or('*'),
+            };
+            let style = if cell.mixed {
+                Style::Default
+            } else {
+                Style::Series(cell.series)
+            };
+            canvas.set(row, left_pad + col, ch, style);
+        }
+    }
+    for (i, col, above) in clipped {
+        let row = if above { 0 } else { rows - 1 };
+        canvas.set(row, left_pad + col, clip_marker(above), Style::Series(i));
+    }
+
+    let legend = |i| marker(config, i).to_string();
+    let below = config.legend_position == LegendPosition::Below;
+    if !config.series_legends.is_empty() && !below {
+        add_side_legends(
+            &mut canvas,
+            config,
+            &ends,
+            left_pad + columns + 1,
+            &[],
+            legend,
+        );
+    }
+    draw_hline_labels(&mut canvas, &layout, left_pad + columns + 1, config);
+    let precision = label_precision(x_min, x_max);
+    draw_x_ticks(&mut canvas, columns, left_pad, config, |column| {
+        let x = if columns > 1 {
+            x_min + (x_max - x_min) * column as f64 / (columns - 1) as f64
+        } else {
+            x_min
+        };
+        match &config.x_label_formatter {
+            Some(formatter) => formatter(x),
+            None => format!("{:.*}", precision, x),
+        }
+    });
+    draw_caption(&mut canvas, config, left_pad, columns);
+    if !config.series_legends.is_empty() && below {
+        add_legends(&mut canvas, config, columns, left_pad, &[], legend);
+    }
+    Ok(canvas)
+}
+
+fn marker(config: &Config, series: usize) -> char {
+    config
+        .markers
+        .get(series)
+        .copied()
+        .unwrap_or_default()
+        .glyph()
+}
@@ -0,0 +1,59 @@
+use crate::{interpolate, min_max, Interpolation};
+
+/// Block levels from lowest to highest.
+const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Draws `series` as a single line of block characters, e.g. `▁▂▅▇▃▁`.
+///
+/// The series is resampled to `width` characters, 0 keeps one character per value. Missing
+/// (NaN) values are drawn as spaces.
+///
+/// ```
+/// assert_eq!(rasciigraph::sparkline(&[1.0, 2.0, 5.0, 7.0, 3.0, 1.0], 0), "▁▂▆█▃▁");
+/// ```
+pub fn sparkline(series: &[f64], width: usize) -> String {
+    if series.is_empty() {
+        return String::new();
+    }
+    let samples = match width {
+        0 => series.to_vec(),
+        1 => vec![series[series.len() - 1]],
+        _ => interpolate(series, width as u32, Interpolation::Linear),
+    };
+    let finite: Vec<f64> = series.iter().copied().filter(|v| v.is_finite()).collect();
+    let (min, max) = min_max(&finite);
+    let interval = max - min;
+    samples
+        .iter()
+        .map(|&value| {
+            if !value.is_finite() {
+                ' '
+            } else if interval > 0f64 {
+                LEVELS[((value - min) / interval * 7f64).round() as usize]
+            } else {
+                LEVELS[0]
+            }
+        })
+        .collect()
+}
+
+/// Draws a [`sparkline`] followed by the minimum, maximum and last value of `series`.
+///
+/// ```
+/// let line = rasciigraph::sparkline_with_summary(&[1.0, 7.0, 3.0], 0, 1);
+/// assert_eq!(line, "▁█▃ min 1.0 max 7.0 last 3.0");
+/// ```
+pub fn sparkline_with_summary(series: &[f64], width: usize, precision: usize) -> String {
+    let line = sparkline(series, width);
+    let finite: Vec<f64> = series.iter().copied().filter(|v| v.is_finite()).collect();
+    match finite.last() {
+        Some(&last) => {
+            let (min, max) = min_max(&finite);
+            format!(
+                "{} min {:.*} max {:.*} last {:.*}",
+                line, precision, min, precision, max, precision, last
+            )
+        }
+        None => line,
+    }
+}
@@ -0,0 +1,196 @@
+/// Glyph set a series is drawn with, telling series apart without colors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SeriesStyle {
+    /// `─` `│` `╭` `╮` `╰` `╯`
+    #[default]
+    Solid,
+    /// `━` `┃` `┏` `┓` `┗` `┛`
+    Heavy,
+    /// `═` `║` `╔` `╗` `╚` `╝`
+    Double,
+    /// `┄` `┆` with rounded corners
+    Dashed,
+    /// `•` for every glyph
+    Dotted,
+    /// `─` `│` `┌` `┐` `└` `┘`, square corners for step series
+    Step,
+}
+
+/// Where series legends are drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LegendPosition {
+    /// On a single line below the plot and caption.
+    #[default]
+    Below,
+    /// One per line to the right of the plot.
+    Right,
+    /// Right of the plot on the line each series ends at.
+    Inline,
+}
+
+pub(crate) struct Glyphs {
+    pub(crate) horizontal: char,
+    pub(crate) vertical: char,
+    /// `╭`
+    pub(crate) down_right: char,
+    /// `╮`
+    pub(crate) down_left: char,
+    /// `╰`
+    pub(crate) up_right: char,
+    /// `╯`
+    pub(crate) up_left: char,
+}
+
+impl SeriesStyle {
+    pub(crate) fn glyphs(self) -> Glyphs {
+        let [horizontal, vertical, down_right, down_left, up_right, up_left] = match self {
+            SeriesStyle::Solid => ['─', '│', '╭', '╮', '╰', '╯'],
+            SeriesStyle::Heavy => ['━', '┃', '┏', '┓', '┗', '┛'],
+            SeriesStyle::Double => ['═', '║', '╔', '╗', '╚', '╝'],
+            SeriesStyle::Dashed => ['┄', '┆', '╭', '╮', '╰', '╯'],
+            SeriesStyle::Dotted => ['•'; 6],
+            SeriesStyle::Step => ['─', '│', '┌', '┐', '└', '┘'],
+        };
+        Glyphs {
+            horizontal,
+            vertical,
+            down_right,
+            down_left,
+            up_right,
+            up_left,
+        }
+    }
+}
+
+/// Order bar charts list their items in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BarOrder {
+    /// As given.
+    #[default]
+    Input,
+    /// Smallest value first.
+    Ascending,
+    /// Largest value first.
+    Descending,
+}
+
+/// Glyph a scatter plot draws the points of a series with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Marker {
+    /// `•`
+    #[default]
+    Dot,
+    /// `×`
+    Cross,
+    /// `+`
+    Plus,
+    /// `o`
+    Circle,
+}
+
+impl Marker {
+    pub(crate) fn glyph(self) -> char {
+        match self {
+            Marker::Dot => '•',
+            Marker::Cross => '×',
+            Marker::Plus => '+',
+            Marker::Circle => 'o',
+        }
+    }
+}
+
+/// How values are mapped onto the rows of the Y axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Scale {
+    #[default]
+    Linear,
+    /// Base 10 logarithm, values must be positive.
+    Log10,
+    /// Base 2 logarithm, values must be positive.
+    Log2,
+    /// `sign(v) * log10(1 + |v|)`, linear around zero and logarithmic away from it.
+    SymLog,
+}
+
+impl Scale {
+    /// Maps a value onto the scale, giving NaN for values outside its domain.
+    pub(crate) fn forward(self, value: f64) -> f64 {
+        match self {
+            Scale::Linear => value,
+            Scale::Log10 if value > 0f64 => value.log10(),
+            Scale::Log2 if value > 0f64 => value.log2(),
+            Scale::Log10 | Scale::Log2 => f64::NAN,
+            Scale::SymLog => value.signum() * value.abs().ln_1p() / std::f64::consts::LN_10,
+        }
+    }
+
+    pub(crate) fn inverse(self, value: f64) -> f64 {
+        match self {
+            Scale::Linear => value,
+            Scale::Log10 => 10f64.powf(value),
+            Scale::Log2 => 2f64.powf(value),
+            Scale::SymLog => value.signum() * (10f64.powf(value.abs()) - 1f64),
+        }
+    }
+}
+
+/// Where annotation texts are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnnotationPosition {
+    /// Above the plot, stacked into as many lines as needed to keep them apart.
+    #[default]
+    Above,
+    /// Numbered above the plot and listed below it.
+    Footnotes,
+}
+
+/// Whether line plots shade the area below their series.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AreaMode {
+    /// Lines only.
+    #[default]
+    Lines,
+    /// Shades between each series and zero, or the nearest edge of the plot when zero is out of
+    /// range.
+    Filled,
+    /// Stacks every series on top of the ones before it, shading the band each one adds.
+    Stacked,
+}
+
+/// How line plot series are resampled to the configured width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Straight lines between neighbouring samples.
+    #[default]
+    Linear,
+    /// Holds each sample until the next one, then jumps. Series drawn without an explicit style
+    /// use [`SeriesStyle::Step`].
+    Step,
+    /// Takes the closest sample.
+    Nearest,
+    /// Places every sample at its own column and leaves the columns between them empty. When
+    /// several samples share a column the last one is kept.
+    None,
+}
+
+/// How line plot series longer than the configured width are reduced to it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Aggregation {
+    /// Resamples with the configured [`Interpolation`], which may skip spikes.
+    #[default]
+    Interpolate,
+    /// Mean of the samples of each column.
+    Mean,
+    /// Lowest sample of each column.
+    Min,
+    /// Highest sample of each column.
+    Max,
+    /// Mean of each column drawn over a vertical line spanning its lowest and highest sample, so
+    /// outliers are never lost.
+    MinMax,
+    /// Last sample of each column.
+    Last,
+    /// Largest-Triangle-Three-Buckets, picking the sample of each column that best keeps the
+    /// shape of the series.
+    Lttb,
+}
@@ -0,0 +1,188 @@
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::{try_plot_many, Config, PlotError};
+
+/// Plots irregularly sampled `(timestamp, value)` points, labeling the X axis with times.
+///
+/// # Panics
+///
+/// Panics on input [`try_plot_time_series`] rejects with a [`PlotError`], except for empty input
+/// and input without finite values, which plot as an empty string.
+pub fn plot_time_series<Tz: TimeZone>(points: &[(DateTime<Tz>, f64)], config: Config) -> String {
+    plot_time_series_many(&[points], config)
+}
+
+/// Plots multiple time series on the same graph, see [`plot_time_series`].
+///
+/// # Panics
+///
+/// Panics on input [`try_plot_time_series_many`] rejects with a [`PlotError`], except for empty
+/// input and input without finite values, which plot as an empty string.
+pub fn plot_time_series_many<Tz: TimeZone>(
+    series: &[&[(DateTime<Tz>, f64)]],
+    config: Config,
+) -> String {
+    crate::unwrap_plot(try_plot_time_series_many(series, config))
+}
+
+/// Fallible version of [`plot_time_series`].
+pub fn try_plot_time_series<Tz: TimeZone>(
+    points: &[(DateTime<Tz>, f64)],
+    config: Config,
+) -> Result<String, PlotError> {
+    try_plot_time_series_many(&[points], config)
+}
+
+/// Fallible version of [`plot_time_series_many`].
+///
+/// Points are placed on the plot columns by their timestamp, the columns being
+/// [`Config::with_width`] or the longest series length wide. Columns holding several points show
+/// their mean. Neighbouring points are joined unless they are more than twice the median
+/// sampling interval of their series apart, such gaps are left empty. The X axis replaces any
+/// configured X labels with times formatted according to the plotted time span.
+pub fn try_plot_time_series_many<Tz: TimeZone>(
+    series: &[&[(DateTime<Tz>, f64)]],
+    mut config: Config,
+) -> Result<String, PlotError> {
+    if let Some(index) = series.iter().position(|s| s.is_empty()) {
+        return Err(PlotError::EmptySeries { index });
+    }
+    if config.width == 1 {
+        return Err(PlotError::InvalidWidth(config.width));
+    }
+
+    // positioned by UTC milliseconds, local times would repeat or skip an hour around DST changes
+    let timezone = series[0][0].0.timezone();
+    let series: Vec<Vec<(i64, f64)>> = series
+        .iter()
+        .map(|s| {
+            let mut points: Vec<(i64, f64)> =
+                s.iter().map(|(t, v)| (t.timestamp_millis(), *v)).collect();
+            points.sort_by_key(|(t, _)| *t);
+            points
+        })
+        .collect();
+
+    let start = series.iter().filter_map(|s| s.first()).map(|p| p.0).min();
+    let end = series.iter().filter_map(|s| s.last()).map(|p| p.0).max();
+    let (start, end) = match (start, end) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return Err(PlotError::NoFiniteValues),
+    };
+    let span = seconds(end - start);
+
+    let columns = if config.width > 0 {
+        config.width as usize
+    } else {
+        usize::max(series.iter().map(|s| s.len()).max().unwrap_or(0), 2)
+    };
+    let column_of = |t: i64| {
+        if span > 0f64 {
+            seconds(t - start) / span * (columns - 1) as f64
+        } else {
+            0f64
+        }
+    };
+    let resampled = series
+        .iter()
+        .map(|s| resample(s, columns, column_of))
+        .collect();
+
+    let step = span / (columns - 1) as f64;
+    let format = time_format(span);
+    // every column labeled in the local time of the first point
+    let labels: Vec<String> = (0..columns)
+        .map(|column| {
+            let millis = start + (column as f64 * step * 1000f64).round() as i64;
+            DateTime::<Utc>::from_timestamp_millis(millis)
+                .map(|t| {
+                    t.with_timezone(&timezone)
+                        .naive_local()
+                        .format(format)
+                        .to_string()
+                })
+                .unwrap_or_default()
+        })
+        .collect();
+    let label_width = labels[0].chars().count() as u32;
+    config.width = 0;
+    config.x_axis = true;
+    config.x_tick_spacing = u32::max(config.x_tick_spacing, label_width + 2);
+    config.x_label_formatter = Some(Box::new(move |column| {
+        labels
+            .get(column.round() as usize)
+            .cloned()
+            .unwrap_or_default()
+    }));
+    try_plot_many(resampled, config)
+}
+
+fn resample<F>(points: &[(i64, f64)], columns: usize, column_of: F) -> Vec<f64>
+where
+    F: Fn(i64) -> f64,
+{
+    let mut sums = vec![0f64; columns];
+    let mut counts = vec![0usize; columns];
+    for (t, v) in points.iter().filter(|(_, v)| !v.is_nan()) {
+        let column = column_of(*t).round() as usize;
+        sums[column] += v;
+        counts[column] += 1;
+    }
+    let mut values: Vec<f64> = sums
+        .iter()
+        .zip(counts.iter())
+        .map(|(sum, &count)| {
+            if count > 0 {
+                sum / count as f64
+            } else {
+                f64::NAN
+            }
+        })
+        .collect();
+
+    // repeated timestamps are not gaps, they would drag the median to zero
+    let mut gaps: Vec<f64> = points
+        .windows(2)
+        .map(|pair| seconds(pair[1].0 - pair[0].0))
+        .filter(|&gap| gap > 0f64)
+        .collect();
+    gaps.sort_by(f64::total_cmp);
+    let max_gap = gaps
+        .get(gaps.len() / 2)
+        .map_or(0f64, |median| median * 2f64);
+
+    for pair in points.windows(2) {
+        let ((t0, v0), (t1, v1)) = (pair[0], pair[1]);
+        if v0.is_nan() || v1.is_nan() || seconds(t1 - t0) > max_gap {
+            continue;
+        }
+        let (x0, x1) = (column_of(t0), column_of(t1));
+        for column in (x0.round() as usize + 1)..(x1.round() as usize) {
+            if counts[column] == 0 {
+                values[column] = v0 + (v1 - v0) * (column as f64 - x0) / (x1 - x0);
+            }
+        }
+    }
+    values
+}
+
+/// Picks a label format precise enough to tell ticks apart over `span` seconds.
+fn time_format(span: f64) -> &'static str {
+    const MINUTE: f64 = 60f64;
+    const DAY: f64 = 24f64 * 60f64 * MINUTE;
+    if span <= 10f64 * MINUTE {
+        "%H:%M:%S"
+    } else if span <= DAY {
+        "%H:%M"
+    } else if span <= 7f64 * DAY {
+        "%m-%d %H:%M"
+    } else if span <= 366f64 * DAY {
+        "%b %d"
+    } else {
+        "%Y-%m-%d"
+    }
+}
+
+fn seconds(millis: i64) -> f64 {
+    millis as f64 / 1000f64
+}